@@ -23,8 +23,8 @@
 //! implements its own set of `FromResidual` impls (these exist so that `DynResult` works the same
 //! way with the `?` operator as `Result`). However, as a result, `DynError`s can only be
 //! constructed with `?` from arbitrary error types when paired with `DynResult`. Using a
-//! `Result<T, DynError>` will require manual conversion of error types due to it missing the
-//! `From` impl that is present on `Box<dyn Error>`.
+//! `Result<T, DynError>` will require manual conversion of error types with `DynError::new` due to
+//! it missing the `From` impl that is present on `Box<dyn Error>`.
 //!
 //! # Examples
 //!
@@ -45,10 +45,16 @@
 //!
 //! Additionally, since `DynError` wraps a `BoxError` type, which is an alias for `Box<dyn Error +
 //! Send + Sync + 'static>`, any error handling API that requires type erased non-thread-safe
-//! errors would not be able to make use of `DynError`.
+//! errors would not be able to make use of `DynError`. Such APIs can use `LocalDynError` and
+//! `LocalDynResult` instead, which drop the `Send + Sync` bounds.
 
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::convert::Infallible;
 use std::error::Error;
 use std::fmt;
+use std::iter::FromIterator;
+use std::panic::Location;
+use std::thread::{self, ThreadId};
 
 type BoxError = Box<dyn Error + Send + Sync + 'static>;
 
@@ -57,6 +63,17 @@ type BoxError = Box<dyn Error + Send + Sync + 'static>;
 pub struct DynError {
     /// The inner wrapped `BoxError`.
     error: BoxError,
+    /// The name of the thread the error was created on, if it had one.
+    thread_name: Option<String>,
+    /// The id of the thread the error was created on.
+    thread_id: ThreadId,
+    /// The backtrace captured when the error was created, used when the wrapped error doesn't
+    /// provide one.
+    backtrace: Backtrace,
+    /// Where in the source the error was created.
+    location: &'static Location<'static>,
+    /// The name of the type of the wrapped error, as it was known when the error was created.
+    type_name: &'static str,
 }
 
 impl fmt::Display for DynError {
@@ -65,21 +82,107 @@ impl fmt::Display for DynError {
     }
 }
 
+/// Converts a type erased error into a `DynError`, reusing it directly if it already is one.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::error::Error;
+/// use trial_and_error::DynError;
+///
+/// let boxed: Box<dyn Error + Send + Sync> = "x".parse::<u32>().unwrap_err().into();
+/// let error = DynError::from(boxed);
+/// assert_eq!(error.to_string(), "invalid digit found in string");
+///
+/// // A `DynError` that was boxed is unwrapped instead of being wrapped a second time
+/// let boxed: Box<dyn Error + Send + Sync> = Box::new(error);
+/// let error = DynError::from(boxed);
+/// assert!(error.downcast_ref::<DynError>().is_none());
+/// ```
+impl From<BoxError> for DynError {
+    #[track_caller]
+    fn from(error: BoxError) -> Self {
+        DynError::from_boxed(error)
+    }
+}
+
 /// This type _does_ implement `Error` 🙌
 impl Error for DynError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.error.source()
     }
+
+    fn backtrace(&self) -> Option<&Backtrace> {
+        let captured = Some(&self.backtrace)
+            .filter(|backtrace| backtrace.status() == BacktraceStatus::Captured);
+
+        self.error.backtrace().or(captured)
+    }
 }
 
 impl DynError {
     /// Create a new `DynError` from an input error.
-    fn new<E>(error: E) -> Self
+    ///
+    /// If `error` is already a `DynError` it is returned as is, rather than being wrapped again.
+    /// Otherwise a backtrace is captured if enabled by the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables, which is returned from `Error::backtrace` when
+    /// the wrapped error doesn't have one of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new(io::Error::new(io::ErrorKind::Other, "disk on fire"));
+    /// assert_eq!(error.to_string(), "disk on fire");
+    /// ```
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// use std::error::Error;
+    /// use trial_and_error::DynError;
+    ///
+    /// std::env::set_var("RUST_LIB_BACKTRACE", "1");
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// assert!(error.backtrace().is_some());
+    /// ```
+    #[track_caller]
+    pub fn new<E>(error: E) -> Self
     where
         E: Error + Send + Sync + 'static,
     {
-        let error = BoxError::from(error);
+        Self::from_boxed_with_name(BoxError::from(error), std::any::type_name::<E>())
+    }
 
+    /// Create a new `DynError` from an already boxed error.
+    ///
+    /// Like `new`, if the boxed error is a `DynError` it is unwrapped rather than being wrapped
+    /// again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new("nope".parse::<u32>().unwrap_err());
+    /// let boxed: Box<dyn Error + Send + Sync + 'static> = Box::new(error);
+    ///
+    /// let error = DynError::from_boxed(boxed);
+    /// assert_eq!(error.to_string(), "invalid digit found in string");
+    /// // The original `DynError` was unwrapped instead of nested inside a new one
+    /// assert!(error.downcast_ref::<DynError>().is_none());
+    /// ```
+    #[track_caller]
+    pub fn from_boxed(error: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        Self::from_boxed_with_name(error, std::any::type_name::<BoxError>())
+    }
+
+    /// Create a new `DynError` from an already boxed error, whose type is named `type_name`.
+    #[track_caller]
+    fn from_boxed_with_name(error: BoxError, type_name: &'static str) -> Self {
         // This logic is necessary for round tripping through `Result<T,
         // BoxError>`, as demonstrated in `fn thing_3()`
         //
@@ -92,11 +195,392 @@ impl DynError {
             // If it is use it directly
             Ok(box error) => error,
             // otherwise create a new `DynError` to wrap the type erased error
-            Err(error) => DynError { error },
+            Err(error) => {
+                let thread = thread::current();
+                DynError {
+                    error,
+                    thread_name: thread.name().map(String::from),
+                    thread_id: thread.id(),
+                    backtrace: Backtrace::capture(),
+                    location: Location::caller(),
+                    type_name,
+                }
+            }
+        }
+    }
+
+    /// Unwraps the `DynError` back into the type erased error it wraps.
+    ///
+    /// `Box<dyn Error + Send + Sync>` already has a blanket `From` impl for every error type,
+    /// `DynError` included, so `BoxError::from` wraps the `DynError` rather than unwrapping it.
+    /// Use this method to get the original box back at API boundaries instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use std::num::ParseIntError;
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// let boxed: Box<dyn Error + Send + Sync> = error.into_inner();
+    /// assert!(boxed.is::<ParseIntError>());
+    /// ```
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync + 'static> {
+        self.error
+    }
+
+    /// Returns a reference to the type erased error this `DynError` wraps.
+    ///
+    /// Use `into_inner` to take ownership of the wrapped error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use std::num::ParseIntError;
+    /// use trial_and_error::DynError;
+    ///
+    /// fn log(error: &dyn Error) -> String {
+    ///     format!("error: {}", error)
+    /// }
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// assert!(error.inner().is::<ParseIntError>());
+    /// assert_eq!(log(error.inner()), "error: invalid digit found in string");
+    ///
+    /// let boxed = error.into_inner();
+    /// assert!(boxed.is::<ParseIntError>());
+    /// ```
+    pub fn inner(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    /// Returns a reference to the wrapped error if it is of type `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::{ParseFloatError, ParseIntError};
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new("nope".parse::<u32>().unwrap_err());
+    ///
+    /// assert!(error.downcast_ref::<ParseIntError>().is_some());
+    /// assert!(error.downcast_ref::<ParseFloatError>().is_none());
+    /// ```
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: Error + 'static,
+    {
+        self.error.downcast_ref::<E>()
+    }
+
+    /// Returns a mutable reference to the wrapped error if it is of type `E`.
+    pub fn downcast_mut<E>(&mut self) -> Option<&mut E>
+    where
+        E: Error + 'static,
+    {
+        self.error.downcast_mut::<E>()
+    }
+
+    /// Attempts to recover the wrapped error as an `E`, returning the `DynError` unchanged if it
+    /// is of a different type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::{ParseFloatError, ParseIntError};
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new("nope".parse::<u32>().unwrap_err());
+    ///
+    /// let error = error.downcast::<ParseFloatError>().unwrap_err();
+    /// let error: ParseIntError = error.downcast().unwrap();
+    /// assert_eq!(error.to_string(), "invalid digit found in string");
+    /// ```
+    pub fn downcast<E>(self) -> Result<E, DynError>
+    where
+        E: Error + 'static,
+    {
+        if !self.error.is::<E>() {
+            return Err(self);
+        }
+
+        match self.error.downcast::<E>() {
+            Ok(box error) => Ok(error),
+            Err(_) => unreachable!("the wrapped error was just checked to be an `E`"),
         }
     }
+
+    /// Iterate over the error chain, starting with this `DynError` followed by each source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::{error::Error, fmt};
+    /// use std::num::ParseIntError;
+    /// use trial_and_error::DynError;
+    ///
+    /// #[derive(Debug)]
+    /// struct ConfigError(ParseIntError);
+    /// #
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("invalid config")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for ConfigError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    ///
+    /// let error = DynError::new(ConfigError("x".parse::<u32>().unwrap_err()));
+    ///
+    /// assert_eq!(error.chain().count(), 2);
+    /// assert!(error.chain().next().unwrap().is::<DynError>());
+    /// ```
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        <dyn Error>::chain(self)
+    }
+
+    /// Wrap this `DynError` in a `Report`.
+    ///
+    /// The report shows the whole chain of the wrapped error, even when it was wrapped in several
+    /// `DynError`s along the way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use trial_and_error::DynError;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let error = DynError::new(DynError::new(Layer("top", Some(Box::new(middle)))));
+    /// let report = error.report();
+    ///
+    /// assert_eq!(report.to_string_compact(), "top: middle: root");
+    /// assert_eq!(
+    ///     report.to_string_pretty(),
+    ///     "top\n\nCaused by:\n   0: middle\n   1: root",
+    /// );
+    /// ```
+    pub fn report(self) -> crate::Report<DynError> {
+        crate::Report::new(self)
+    }
+
+    /// The name of the thread this error was created on, if the thread was named.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::thread;
+    /// use trial_and_error::DynResult;
+    ///
+    /// fn parse() -> DynResult<u32> {
+    ///     let parsed = "four".parse::<u32>()?;
+    ///
+    ///     DynResult::Ok(parsed)
+    /// }
+    ///
+    /// let error = thread::Builder::new()
+    ///     .name(String::from("worker-3"))
+    ///     .spawn(|| match parse() {
+    ///         DynResult::Ok(_) => unreachable!(),
+    ///         DynResult::Err(error) => error,
+    ///     })
+    ///     .unwrap()
+    ///     .join()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(error.thread_name(), Some("worker-3"));
+    /// ```
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    /// The id of the thread this error was created on.
+    pub fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    /// Where in the source this error was created, either by `DynError::new` or by a `?` in a
+    /// function returning a `DynResult`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// assert_eq!(error.location().file(), file!());
+    /// assert_eq!(error.location().line(), line!() - 2);
+    ///
+    /// fn parse() -> DynResult<u32> {
+    ///     DynResult::Ok("x".parse::<u32>()?)
+    /// }
+    /// let line = line!() - 2;
+    ///
+    /// let error = parse().unwrap_err();
+    /// assert_eq!(error.location().line(), line);
+    /// ```
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// The name of the type of the wrapped error, as returned by `std::any::type_name`.
+    ///
+    /// Only the type passed to `DynError::new`, or converted with `?`, is known. An error that was
+    /// already type erased when it was wrapped, as with `DynError::from_boxed`, is named after
+    /// its box. Like `std::any::type_name`, the exact name is meant for diagnostics and may
+    /// change between compiler versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::error::Error;
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// assert_eq!(error.type_name(), "core::num::error::ParseIntError");
+    ///
+    /// fn parse() -> DynResult<f32> {
+    ///     DynResult::Ok("x".parse::<f32>()?)
+    /// }
+    /// assert!(parse().unwrap_err().type_name().ends_with("ParseFloatError"));
+    ///
+    /// let boxed: Box<dyn Error + Send + Sync> = "x".parse::<u32>().unwrap_err().into();
+    /// assert!(DynError::from_boxed(boxed).type_name().starts_with("alloc::boxed::Box<dyn"));
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The process exit code declared by a `WithExitCode` in this error's chain, if any.
+    fn exit_code(&self) -> Option<i32> {
+        let error: &(dyn Error + 'static) = &*self.error;
+        error
+            .chain()
+            .find_map(|error| error.downcast_ref::<WithExitCode>())
+            .map(WithExitCode::code)
+    }
+}
+
+/// Wrapper that declares the process exit code to use when an error is returned from `main`.
+///
+/// `WithExitCode` is transparent: it displays as the error it wraps and exposes that error's
+/// source as its own. When a `DynResult` returned from `main` holds an error whose chain contains
+/// a `WithExitCode`, its code is used as the exit code instead of the default of 1.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(termination_trait_lib)]
+/// use std::process::Termination;
+/// use trial_and_error::DynResult;
+/// use trial_and_error::boxerror_replacement::WithExitCode;
+///
+/// // As defined in `sysexits.h`
+/// const EX_USAGE: i32 = 64;
+///
+/// fn run() -> DynResult<()> {
+///     let error = "many".parse::<u32>().unwrap_err();
+///     Err::<(), _>(WithExitCode::new(EX_USAGE, error))?;
+///
+///     DynResult::Ok(())
+/// }
+///
+/// assert_eq!(run().report(), EX_USAGE);
+/// ```
+#[derive(Debug)]
+pub struct WithExitCode {
+    /// The exit code to use for the process.
+    code: i32,
+    /// The error being wrapped.
+    error: BoxError,
+}
+
+impl WithExitCode {
+    /// Wrap `error`, declaring that the process should exit with `code` if it is reported.
+    pub fn new<E>(code: i32, error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        WithExitCode {
+            code,
+            error: BoxError::from(error),
+        }
+    }
+
+    /// The exit code declared for the wrapped error.
+    pub fn code(&self) -> i32 {
+        self.code
+    }
+}
+
+impl fmt::Display for WithExitCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
 }
 
+impl Error for WithExitCode {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+
+    fn backtrace(&self) -> Option<&Backtrace> {
+        self.error.backtrace()
+    }
+}
+
+/// The error produced when `?` is used on a `None` in a function returning a `DynResult`.
+///
+/// # Examples
+///
+/// ```rust
+/// use trial_and_error::boxerror_replacement::NoneError;
+/// use trial_and_error::DynResult;
+///
+/// fn first_word(s: &str) -> DynResult<&str> {
+///     let word = s.split_whitespace().next()?;
+///     DynResult::Ok(word)
+/// }
+///
+/// assert_eq!(first_word("hello world").ok(), Some("hello"));
+///
+/// let error = first_word("   ").unwrap_err();
+/// assert!(error.downcast_ref::<NoneError>().is_some());
+/// assert_eq!(error.to_string(), "expected a value but found `None`");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoneError;
+
+impl fmt::Display for NoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a value but found `None`")
+    }
+}
+
+impl Error for NoneError {}
+
 use std::ops::{ControlFlow, FromResidual, Try};
 use std::process::Termination;
 
@@ -108,19 +592,279 @@ pub enum DynResult<T> {
     Err(DynError),
 }
 
+impl<T> DynResult<T> {
+    /// Converts a `Result` into a `DynResult`, wrapping its error in a `DynError`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::DynResult;
+    ///
+    /// let parsed = DynResult::from_result("4".parse::<u32>());
+    /// assert_eq!(parsed.ok(), Some(4));
+    ///
+    /// let parsed = DynResult::from_result("four".parse::<u32>());
+    /// assert_eq!(parsed.unwrap_err().to_string(), "invalid digit found in string");
+    /// ```
+    #[track_caller]
+    pub fn from_result<E>(result: Result<T, E>) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        match result {
+            Ok(value) => DynResult::Ok(value),
+            Err(error) => DynResult::Err(DynError::new(error)),
+        }
+    }
+
+    /// Converts the `DynResult` into a `Result` with a `DynError` as its error type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::ParseIntError;
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// fn parse(s: &str) -> DynResult<u32> {
+    ///     DynResult::Ok(s.parse::<u32>()?)
+    /// }
+    ///
+    /// let parsed: Result<u32, DynError> = parse("4").into_result();
+    /// assert_eq!(parsed.unwrap(), 4);
+    ///
+    /// let error = parse("four").into_result().unwrap_err();
+    /// assert!(error.downcast_ref::<ParseIntError>().is_some());
+    /// ```
+    pub fn into_result(self) -> Result<T, DynError> {
+        match self {
+            DynResult::Ok(value) => Ok(value),
+            DynResult::Err(error) => Err(error),
+        }
+    }
+
+    /// Returns `true` if the result is `Ok`.
+    pub fn is_ok(&self) -> bool {
+        matches!(self, DynResult::Ok(_))
+    }
+
+    /// Returns `true` if the result is `Err`.
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Converts the result into an `Option<T>`, discarding the error, if any.
+    pub fn ok(self) -> Option<T> {
+        match self {
+            DynResult::Ok(value) => Some(value),
+            DynResult::Err(_) => None,
+        }
+    }
+
+    /// Converts the result into an `Option<DynError>`, discarding the success value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Ok(2);
+    /// assert!(parsed.err().is_none());
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Err(DynError::new("x".parse::<u32>().unwrap_err()));
+    /// assert_eq!(parsed.err().unwrap().to_string(), "invalid digit found in string");
+    /// ```
+    pub fn err(self) -> Option<DynError> {
+        match self {
+            DynResult::Ok(_) => None,
+            DynResult::Err(error) => Some(error),
+        }
+    }
+
+    /// Converts from `&DynResult<T>` to `Result<&T, &DynError>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Ok(2);
+    /// assert_eq!(parsed.as_ref().ok(), Some(&2));
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Err(DynError::new("x".parse::<u32>().unwrap_err()));
+    /// let error = parsed.as_ref().unwrap_err();
+    /// assert_eq!(error.to_string(), "invalid digit found in string");
+    /// assert!(parsed.is_err());
+    /// ```
+    pub fn as_ref(&self) -> Result<&T, &DynError> {
+        match self {
+            DynResult::Ok(value) => Ok(value),
+            DynResult::Err(error) => Err(error),
+        }
+    }
+
+    /// Returns an iterator over the `Ok` value, which yields nothing if the result is an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Ok(5);
+    /// assert_eq!(parsed.iter().next(), Some(&5));
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Err(DynError::new("x".parse::<u32>().unwrap_err()));
+    /// assert_eq!(parsed.iter().next(), None);
+    /// ```
+    pub fn iter(&self) -> std::option::IntoIter<&T> {
+        self.as_ref().ok().into_iter()
+    }
+
+    /// Maps a `DynResult<T>` to `DynResult<U>` by applying a function to a contained `Ok` value,
+    /// leaving an `Err` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::DynResult;
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Ok(2);
+    /// assert_eq!(parsed.map(|n| n * 2).ok(), Some(4));
+    /// ```
+    pub fn map<U, F>(self, op: F) -> DynResult<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            DynResult::Ok(value) => DynResult::Ok(op(value)),
+            DynResult::Err(error) => DynResult::Err(error),
+        }
+    }
+
+    /// Maps a `DynResult<T>` to `Result<T, F>` by applying a function to a contained `DynError`,
+    /// leaving an `Ok` untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use trial_and_error::{DynError, DynResult};
+    ///
+    /// let parsed: DynResult<u32> = DynResult::Err(DynError::new("x".parse::<u32>().unwrap_err()));
+    /// let message = parsed.map_err(|error| error.to_string()).unwrap_err();
+    /// assert_eq!(message, "invalid digit found in string");
+    /// ```
+    pub fn map_err<F, O>(self, op: O) -> Result<T, F>
+    where
+        O: FnOnce(DynError) -> F,
+    {
+        match self {
+            DynResult::Ok(value) => Ok(value),
+            DynResult::Err(error) => Err(op(error)),
+        }
+    }
+
+    /// Calls `op` with the `Ok` value, or returns the `Err` untouched.
+    pub fn and_then<U, F>(self, op: F) -> DynResult<U>
+    where
+        F: FnOnce(T) -> DynResult<U>,
+    {
+        match self {
+            DynResult::Ok(value) => op(value),
+            DynResult::Err(error) => DynResult::Err(error),
+        }
+    }
+
+    /// Returns the `Ok` value, panicking with `msg` and the error if the result is an `Err`.
+    #[track_caller]
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            DynResult::Ok(value) => value,
+            DynResult::Err(error) => unwrap_failed(msg, &error),
+        }
+    }
+
+    /// Returns the `Ok` value, panicking with the error if the result is an `Err`.
+    #[track_caller]
+    pub fn unwrap(self) -> T {
+        match self {
+            DynResult::Ok(value) => value,
+            DynResult::Err(error) => {
+                unwrap_failed("called `DynResult::unwrap()` on an `Err` value", &error)
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug> DynResult<T> {
+    /// Returns the contained `DynError`, panicking with the value if the result is an `Ok`.
+    #[track_caller]
+    pub fn unwrap_err(self) -> DynError {
+        match self {
+            DynResult::Ok(value) => {
+                unwrap_failed("called `DynResult::unwrap_err()` on an `Ok` value", &value)
+            }
+            DynResult::Err(error) => error,
+        }
+    }
+}
+
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn unwrap_failed(msg: &str, error: &dyn fmt::Debug) -> ! {
+    panic!("{}: {:?}", msg, error)
+}
+
 impl<T> Termination for DynResult<T> {
     /// Return an error code corresponding with the `DynResult`; 0 for success, 1 for failure.
+    ///
+    /// Failures use the exit code declared by a `WithExitCode` in the error's chain instead, if
+    /// there is one.
     fn report(self) -> i32 {
         match self {
             DynResult::Ok(_) => 0,
             DynResult::Err(error) => {
-                eprintln!("Error: {:?}", crate::Report::new(error));
-                1
+                let code = error.exit_code().unwrap_or(1);
+                let report = crate::Report::new(error);
+                // Single line reports can't show a backtrace, so switch to the multi-line format
+                // whenever there is one to show
+                if report.backtrace().is_some() {
+                    eprintln!("Error: {}", report.pretty().show_backtrace());
+                } else {
+                    eprintln!("Error: {}", report);
+                }
+                code
             }
         }
     }
 }
 
+/// Runs `f`, reporting its error to stderr the same way returning it from `main` would, and
+/// returns the exit code the program should end with.
+///
+/// This is meant for programs that do their work in a separate function called from `main`, and
+/// end with `std::process::exit`.
+///
+/// # Examples
+///
+/// ```rust
+/// use trial_and_error::boxerror_replacement;
+/// use trial_and_error::DynResult;
+///
+/// fn real_main() -> DynResult<()> {
+///     let _parsed = "four".parse::<u32>()?;
+///     DynResult::Ok(())
+/// }
+///
+/// assert_eq!(boxerror_replacement::run(real_main), 1);
+/// assert_eq!(boxerror_replacement::run(|| DynResult::Ok(())), 0);
+/// ```
+pub fn run<T, F>(f: F) -> i32
+where
+    F: FnOnce() -> DynResult<T>,
+{
+    f().report()
+}
+
 // Implements `Try` on `DynResult` so that the `?` operator can be used on it
 impl<T> Try for DynResult<T> {
     type Output = T;
@@ -140,18 +884,50 @@ impl<T> Try for DynResult<T> {
     }
 }
 
-// Given a `Result::Err(E)`, convert it to a `DynResult::Err(E)`
-impl<T, E> FromResidual<Result<!, E>> for DynResult<T>
+/// Given a `Result::Err(E)`, convert it to a `DynResult::Err(E)` by wrapping `E` in a `DynError`.
+///
+/// Using `?` on a `Result<T, DynError>` moves the `DynError` through as is, instead of wrapping it
+/// in another `DynError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::ParseIntError;
+/// use trial_and_error::{DynError, DynResult};
+///
+/// fn parse(digits: &str) -> Result<u32, DynError> {
+///     digits.parse().map_err(DynError::new)
+/// }
+///
+/// fn run() -> DynResult<u32> {
+///     let parsed = parse("four")?;
+///     DynResult::Ok(parsed)
+/// }
+///
+/// let error = run().unwrap_err();
+/// assert!(error.downcast_ref::<ParseIntError>().is_some());
+/// assert!(error.downcast_ref::<DynError>().is_none());
+/// ```
+impl<T, E> FromResidual<Result<Infallible, E>> for DynResult<T>
 where
     E: Error + Send + Sync + 'static,
 {
-    fn from_residual(inner: Result<!, E>) -> Self {
+    #[track_caller]
+    fn from_residual(inner: Result<Infallible, E>) -> Self {
         let Err(error) = inner;
         let error = DynError::new(error);
         DynResult::Err(error)
     }
 }
 
+// Given an `Option::None`, convert it to a `DynResult::Err(NoneError)`
+impl<T> FromResidual<Option<Infallible>> for DynResult<T> {
+    #[track_caller]
+    fn from_residual(_: Option<Infallible>) -> Self {
+        DynResult::Err(DynError::new(NoneError))
+    }
+}
+
 // Given a `DynResult` emitted by a `?`, convert it to a `DynResult::Err(E)`
 impl<T> FromResidual<DynResult<!>> for DynResult<T> {
     fn from_residual(residual: DynResult<!>) -> Self {
@@ -160,11 +936,258 @@ impl<T> FromResidual<DynResult<!>> for DynResult<T> {
     }
 }
 
-// Given a `DynResult` emitted by a `?`, convert it to a `Result::Err(E)`
+/// Given a `DynResult` emitted by a `?`, convert it to a `Result::Err(E)` by unwrapping the
+/// `DynError` back into the error it wraps.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+/// use trial_and_error::DynResult;
+///
+/// fn parse(digits: &str) -> DynResult<u32> {
+///     let parsed = digits.parse::<u32>()?;
+///     DynResult::Ok(parsed)
+/// }
+///
+/// fn run() -> Result<u32, Box<dyn Error + Send + Sync>> {
+///     let parsed = parse("four")?;
+///     Ok(parsed)
+/// }
+///
+/// assert!(run().unwrap_err().is::<ParseIntError>());
+/// ```
 impl<T> FromResidual<DynResult<!>> for Result<T, BoxError> {
     fn from_residual(residual: DynResult<!>) -> Self {
         let DynResult::Err(error) = residual;
-        let error = BoxError::from(error);
-        Err(error)
+        Err(error.into_inner())
+    }
+}
+
+/// Collect an iterator of `Result`s into a `DynResult`, the same way `Result` collects.
+///
+/// Iteration stops at the first error, which is converted into a `DynError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use trial_and_error::DynResult;
+///
+/// let parsed: DynResult<Vec<u32>> = ["1", "2", "3"].iter().map(|s| s.parse::<u32>()).collect();
+/// assert!(matches!(parsed, DynResult::Ok(v) if v == [1, 2, 3]));
+///
+/// let parsed: DynResult<Vec<u32>> = ["1", "two", "3"].iter().map(|s| s.parse::<u32>()).collect();
+/// assert!(matches!(parsed, DynResult::Err(e) if e.to_string() == "invalid digit found in string"));
+/// ```
+impl<T, E, V> FromIterator<Result<T, E>> for DynResult<V>
+where
+    E: Error + Send + Sync + 'static,
+    V: FromIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        match iter.into_iter().collect::<Result<V, E>>() {
+            Ok(value) => DynResult::Ok(value),
+            Err(error) => DynResult::Err(DynError::new(error)),
+        }
+    }
+}
+
+/// Collect an iterator of `DynResult`s into a single `DynResult`, stopping at the first error.
+///
+/// # Examples
+///
+/// ```rust
+/// use trial_and_error::DynResult;
+///
+/// fn parse(s: &str) -> DynResult<u32> {
+///     DynResult::Ok(s.parse::<u32>()?)
+/// }
+///
+/// let parsed: DynResult<Vec<u32>> = ["1", "2"].iter().copied().map(parse).collect();
+/// assert!(matches!(parsed, DynResult::Ok(v) if v == [1, 2]));
+///
+/// let parsed: DynResult<Vec<u32>> = ["1", "two", "3"].iter().copied().map(parse).collect();
+/// assert!(matches!(parsed, DynResult::Err(_)));
+/// ```
+impl<T, V> FromIterator<DynResult<T>> for DynResult<V>
+where
+    V: FromIterator<T>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = DynResult<T>>,
+    {
+        let collected = iter
+            .into_iter()
+            .map(DynResult::into_result)
+            .collect::<Result<V, DynError>>();
+
+        match collected {
+            Ok(value) => DynResult::Ok(value),
+            Err(error) => DynResult::Err(error),
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Iterating over a `DynResult` yields the `Ok` value, if any, like iterating over a `Result`.
+///
+/// # Examples
+///
+/// ```rust
+/// use trial_and_error::{DynError, DynResult};
+///
+/// let parsed: DynResult<u32> = DynResult::Ok(5);
+/// assert_eq!(parsed.into_iter().collect::<Vec<_>>(), [5]);
+///
+/// let parsed: DynResult<u32> = DynResult::Err(DynError::new("x".parse::<u32>().unwrap_err()));
+/// assert_eq!(parsed.into_iter().count(), 0);
+/// ```
+impl<T> IntoIterator for DynResult<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ok().into_iter()
+    }
+}
+
+type LocalBoxError = Box<dyn Error + 'static>;
+
+/// Owning type for a `Box<dyn Error>` that, unlike `DynError`, doesn't require the wrapped error
+/// to be `Send` or `Sync`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::{error::Error, fmt, rc::Rc};
+/// use trial_and_error::{LocalDynError, LocalDynResult};
+///
+/// #[derive(Debug)]
+/// struct Shared(Rc<str>);
+/// # impl fmt::Display for Shared {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         f.write_str(&self.0)
+/// #     }
+/// # }
+/// # impl Error for Shared {}
+///
+/// fn load() -> Result<(), Shared> {
+///     Err(Shared(Rc::from("shared state poisoned")))
+/// }
+///
+/// fn run() -> LocalDynResult<()> {
+///     load()?;
+///     LocalDynResult::Ok(())
+/// }
+///
+/// let error: LocalDynError = match run() {
+///     LocalDynResult::Ok(()) => unreachable!(),
+///     LocalDynResult::Err(error) => error,
+/// };
+/// assert_eq!(error.to_string(), "shared state poisoned");
+/// ```
+#[derive(Debug)]
+pub struct LocalDynError {
+    /// The inner wrapped `Box<dyn Error>`.
+    error: LocalBoxError,
+}
+
+impl fmt::Display for LocalDynError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Error for LocalDynError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl LocalDynError {
+    /// Wraps `error` in a `LocalDynError`, reusing it directly if it already is one.
+    pub fn new<E>(error: E) -> Self
+    where
+        E: Error + 'static,
+    {
+        Self::from_boxed(LocalBoxError::from(error))
+    }
+
+    /// Wraps an already type erased error, reusing it directly if it already is a
+    /// `LocalDynError`.
+    pub fn from_boxed(error: Box<dyn Error + 'static>) -> Self {
+        match error.downcast::<LocalDynError>() {
+            Ok(box error) => error,
+            Err(error) => LocalDynError { error },
+        }
+    }
+}
+
+/// Result that always converts error types to a `LocalDynError`.
+pub enum LocalDynResult<T> {
+    /// The Ok variant of the `LocalDynResult`.
+    Ok(T),
+    /// The Err variant of the `LocalDynResult` containing a `LocalDynError`.
+    Err(LocalDynError),
+}
+
+impl<T> Termination for LocalDynResult<T> {
+    /// Return an error code corresponding with the `LocalDynResult`; 0 for success, 1 for failure.
+    fn report(self) -> i32 {
+        match self {
+            LocalDynResult::Ok(_) => 0,
+            LocalDynResult::Err(error) => {
+                eprintln!("Error: {}", crate::Report::new(error));
+                1
+            }
+        }
+    }
+}
+
+// Implements `Try` on `LocalDynResult` the same way `DynResult` does
+impl<T> Try for LocalDynResult<T> {
+    type Output = T;
+    type Residual = LocalDynResult<!>;
+
+    fn from_output(value: T) -> Self {
+        LocalDynResult::Ok(value)
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self {
+            LocalDynResult::Ok(value) => ControlFlow::Continue(value),
+            LocalDynResult::Err(error) => ControlFlow::Break(LocalDynResult::Err(error)),
+        }
+    }
+}
+
+// Given a `Result::Err(E)`, convert it to a `LocalDynResult::Err(E)`
+impl<T, E> FromResidual<Result<Infallible, E>> for LocalDynResult<T>
+where
+    E: Error + 'static,
+{
+    fn from_residual(inner: Result<Infallible, E>) -> Self {
+        let Err(error) = inner;
+        LocalDynResult::Err(LocalDynError::new(error))
+    }
+}
+
+// Given a `LocalDynResult` emitted by a `?`, convert it to a `LocalDynResult::Err(E)`
+impl<T> FromResidual<LocalDynResult<!>> for LocalDynResult<T> {
+    fn from_residual(residual: LocalDynResult<!>) -> Self {
+        let LocalDynResult::Err(error) = residual;
+        LocalDynResult::Err(error)
+    }
+}
+
+// Given a `LocalDynResult` emitted by a `?`, convert it to a `Result::Err(E)`
+impl<T> FromResidual<LocalDynResult<!>> for Result<T, LocalBoxError> {
+    fn from_residual(residual: LocalDynResult<!>) -> Self {
+        let LocalDynResult::Err(error) = residual;
+        Err(LocalBoxError::from(error))
+    }
+}
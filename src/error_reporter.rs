@@ -322,169 +322,5340 @@
 //! Stack backtrace:
 //! disabled backtrace
 //! ```
+//!
+//! # Allocation
+//!
+//! Rendering a report in the single-line format never allocates, as long as no redactions are
+//! configured, the `prose`, `dedup`, `wrap_at`, and `skip_redundant` options are off, and no
+//! message length limit is set, so a `Report` can be written into a fixed size buffer in
+//! environments where allocating is not an option. The multi-line format also writes straight
+//! through to the output, except for the backtrace, which is rendered into a `String` before it is
+//! written. The `dedup` option renders each cause into a `String` to compare it with the next one,
+//! and `skip_redundant` keeps a copy of the single-line output to compare each cause against. The
+//! `max_message_chars` limit and `catch_panics` render each message into a `String` before
+//! writing it, and `catch_panics` renders the whole report into one as well.
+//! Redaction and the `max_bytes` limit both render the whole report into a `String`
+//! before post-processing it, in either format.
+//!
+//! ```rust
+//! use std::alloc::{GlobalAlloc, Layout, System};
+//! use std::fmt::{self, Write};
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! # use std::error::Error;
+//!
+//! use trial_and_error::Report;
+//!
+//! struct CountingAllocator;
+//!
+//! static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! unsafe impl GlobalAlloc for CountingAllocator {
+//!     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+//!         ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+//!         System.alloc(layout)
+//!     }
+//!
+//!     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+//!         System.dealloc(ptr, layout)
+//!     }
+//! }
+//!
+//! #[global_allocator]
+//! static GLOBAL: CountingAllocator = CountingAllocator;
+//!
+//! /// A `fmt::Write` sink backed by a buffer on the stack.
+//! struct StackBuffer {
+//!     buf: [u8; 64],
+//!     len: usize,
+//! }
+//!
+//! impl Write for StackBuffer {
+//!     fn write_str(&mut self, s: &str) -> fmt::Result {
+//!         let end = self.len + s.len();
+//!         let dest = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+//!         dest.copy_from_slice(s.as_bytes());
+//!         self.len = end;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # #[derive(Debug)]
+//! # struct SuperErrorSidekick;
+//! #
+//! # impl fmt::Display for SuperErrorSidekick {
+//! #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//! #         write!(f, "SuperErrorSidekick is here!")
+//! #     }
+//! # }
+//! #
+//! # impl Error for SuperErrorSidekick {}
+//! #
+//! fn main() {
+//!     let report = Report::new(SuperErrorSidekick);
+//!     let mut sink = StackBuffer { buf: [0; 64], len: 0 };
+//!
+//!     let before = ALLOCATIONS.load(Ordering::SeqCst);
+//!     write!(sink, "{}", report).unwrap();
+//!     assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+//!
+//!     let rendered = std::str::from_utf8(&sink.buf[..sink.len]).unwrap();
+//!     assert_eq!(rendered, "SuperErrorSidekick is here!");
+//! }
+//! ```
+
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    borrow::Cow,
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Write},
+    io,
+    iter::FromIterator,
+    lazy::SyncOnceCell,
+    ops::Deref,
+    panic::{self, AssertUnwindSafe},
+    process::Termination,
+    sync::Arc,
+};
+
+#[cfg(feature = "dyn_error")]
+use crate::DynError;
+
+/// The main `Report` type.
+pub struct Report<E> {
+    /// The error being reported.
+    source: E,
+    /// Whether the full backtrace should be included as part of the report.
+    show_backtrace: bool,
+    /// The format the report is rendered in.
+    format: ReportFormat,
+    /// Whether details about the environment should be appended to the report.
+    env_context: bool,
+    /// The application version included with the environment details.
+    env_context_version: Option<&'static str>,
+    /// Whether each error should be rendered with the alternate `Display` format.
+    alternate_inner: bool,
+    /// The thread the error originated on, if it should be included in the report.
+    thread: Option<String>,
+    /// Secret strings that must be redacted from the rendered report.
+    redactions: Vec<String>,
+    /// Wraps each error before it is rendered, replacing its `Display` output.
+    decorator: Option<Decorator>,
+    /// Formats each error in place of its `Display` impl.
+    format_each: Option<FormatEach>,
+    /// Views the wrapped error as the `'static` trait object the hooks are given, which is only
+    /// possible once its type is known to be `'static`.
+    static_source: Option<fn(&E) -> &(dyn Error + 'static)>,
+    /// Whether the single line format should join causes as a prose sentence.
+    prose: bool,
+    /// Bounds on how much of the error chain is rendered.
+    limits: Limits,
+    /// The number of spaces causes are indented by in the multi-line format.
+    indent_width: usize,
+    /// A backtrace captured by the report itself, used when the error doesn't provide one.
+    backtrace: Option<Arc<Backtrace>>,
+    /// A backtrace supplied by the user, used in place of the error's own backtrace.
+    supplied_backtrace: Option<Arc<Backtrace>>,
+    /// Whether the multi-line format should be styled with ANSI escape codes.
+    color: bool,
+    /// The header introducing the causes in the multi-line format.
+    cause_header: &'static str,
+    /// Whether runs of consecutive causes with identical messages are collapsed into one.
+    dedup: bool,
+    /// The string placed between errors in the single-line format.
+    separator: &'static str,
+    /// Whether the backtraces of the causes are shown along with the wrapped error's.
+    all_backtraces: bool,
+    /// Whether causes are only numbered when there are more than two of them.
+    compact_numbering: bool,
+    /// Whether each error is rendered with `Debug` instead of `Display`.
+    debug_chain: bool,
+    /// The column the single-line format wraps at, if any.
+    wrap_at: Option<usize>,
+    /// Decides which lines of a backtrace are shown.
+    backtrace_filter: Option<BacktraceFilter>,
+    /// Whether the single-line format leaves out causes whose message already ends the output.
+    skip_redundant: bool,
+    /// The string every line of the report starts with, if any.
+    line_prefix: Option<String>,
+    /// Text shown at the end of the multi-line format, if any.
+    footer: Option<String>,
+    /// Whether the blocks of the multi-line format are separated without a blank line.
+    compact_spacing: bool,
+    /// Whether the multi-line format starts with the root cause and walks outward.
+    reverse: bool,
+    /// Offers the advice shown below each error in the multi-line format.
+    help: Option<Help>,
+    /// Whether the top error of the multi-line format is indented like its causes.
+    indent_all: bool,
+    /// The number of spaces every line of the report is indented by.
+    base_indent: usize,
+    /// Whether the multi-line format explains how to enable backtraces when there is none to show.
+    backtrace_hint: bool,
+    /// Whether panics from the errors while formatting them are caught.
+    catch_panics: bool,
+}
+
+/// Shown in place of an error that panicked while it was being formatted.
+const PANIC_MARKER: &str = "<panic while formatting error>";
+
+/// The number of spaces causes are indented by in the multi-line format, unless configured
+/// otherwise.
+const DEFAULT_INDENT_WIDTH: usize = 4;
+
+/// The formats a `Report` can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// The wrapped error and all of its causes on one line, separated by colons. This is the
+    /// default.
+    SingleLine,
+    /// The wrapped error followed by each cause on its own line, as enabled by `Report::pretty`.
+    MultiLine,
+}
+
+/// A user supplied function for decorating each error in a report.
+type Decorator =
+    Arc<dyn for<'a> Fn(&'a (dyn Error + 'static)) -> Box<dyn fmt::Display + 'a> + Send + Sync>;
+
+/// A user supplied predicate for the lines of a backtrace that are shown.
+type BacktraceFilter = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// A user supplied function for formatting each error in a report.
+type FormatEach =
+    Arc<dyn Fn(&(dyn Error + 'static), &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync>;
+
+/// A user supplied function for the remediation advice shown for each error in a report.
+type Help = Arc<dyn Fn(&dyn Error) -> Option<String> + Send + Sync>;
+
+/// Bounds on how much of an error chain a `Report` renders.
+///
+/// Every limit defaults to `None`, meaning unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum number of causes rendered below the wrapped error.
+    ///
+    /// Causes past the limit are summarized by a `...` marker, which in the multi-line format also
+    /// includes the number of causes left out.
+    pub max_depth: Option<usize>,
+    /// The maximum number of bytes of rendered output.
+    ///
+    /// This is a hard stop applied to the complete report after every other option, including
+    /// redaction and line prefixes. A report that would exceed it is cut short, without splitting
+    /// a character, and ends with `...` so that the whole report still fits within the limit.
+    pub max_bytes: Option<usize>,
+    /// The maximum number of characters of each individual error's message.
+    ///
+    /// A longer message is cut short and ends with `...`, so that it still fits within the limit.
+    pub max_message_chars: Option<usize>,
+    /// The maximum number of errors rendered by a `ReportGroup`.
+    ///
+    /// Errors past the limit are summarized by a `... and N more` line. A `Report` is unaffected,
+    /// since it only ever renders a single error.
+    pub max_children: Option<usize>,
+}
+
+/// A snapshot of the formatting options of a `Report`, as returned by `Report::options`.
+///
+/// The same options can be used to configure new reports, either one at a time through
+/// `Report::with_options`, or for every report created by `Report::new` through
+/// `set_default_options`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReportOptions {
+    /// The format the report is rendered in.
+    pub format: ReportFormat,
+    /// Whether the report includes a backtrace.
+    pub show_backtrace: bool,
+}
+
+impl Default for ReportOptions {
+    /// The options of a `Report` that hasn't been configured, rendering in the single-line format
+    /// without a backtrace.
+    fn default() -> Self {
+        ReportOptions {
+            format: ReportFormat::SingleLine,
+            show_backtrace: false,
+        }
+    }
+}
+
+/// The options `Report::new` starts reports out with, if set.
+static DEFAULT_OPTIONS: SyncOnceCell<ReportOptions> = SyncOnceCell::new();
+
+/// Set the options every report created by `Report::new` starts out with, for the rest of the
+/// program.
+///
+/// The default options can only be set once. If they already were, `options` is handed back as the
+/// error. Reports created before the defaults were set keep their options.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// use trial_and_error::error_reporter::{self, ReportFormat, ReportOptions};
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError {
+/// #     side: SuperErrorSidekick,
+/// # }
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         Some(&self.side)
+/// #     }
+/// # }
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperErrorSidekick;
+/// #
+/// # impl fmt::Display for SuperErrorSidekick {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperErrorSidekick is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperErrorSidekick {}
+/// let error = || SuperError { side: SuperErrorSidekick };
+/// assert_eq!(
+///     Report::new(error()).to_string(),
+///     "SuperError is here!: SuperErrorSidekick is here!",
+/// );
+///
+/// let mut options = ReportOptions::default();
+/// options.format = ReportFormat::MultiLine;
+/// error_reporter::set_default_options(options).unwrap();
+///
+/// assert_eq!(
+///     Report::new(error()).to_string(),
+///     "SuperError is here!\n\nCaused by:\n    SuperErrorSidekick is here!",
+/// );
+/// assert_eq!(Report::new(error()).options(), options);
+/// assert!(error_reporter::set_default_options(ReportOptions::default()).is_err());
+/// ```
+pub fn set_default_options(options: ReportOptions) -> Result<(), ReportOptions> {
+    DEFAULT_OPTIONS.set(options)
+}
+
+/// An error that adds a message of context on top of another error, as created by
+/// `Report::context`.
+pub struct Context<C, E> {
+    context: C,
+    error: E,
+}
+
+impl<C, E> Context<C, E> {
+    /// Returns a reference to the context message.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Returns a reference to the error the context was added to.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+}
+
+impl<C, E> fmt::Debug for Context<C, E>
+where
+    C: fmt::Display,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("context", &format_args!("{}", self.context))
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<C, E> fmt::Display for Context<C, E>
+where
+    C: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.context.fmt(f)
+    }
+}
+
+impl<C, E> Error for Context<C, E>
+where
+    C: fmt::Display,
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A type erased error that forwards to the boxed error, as created by `Report::from_boxed`.
+pub struct BoxedError(Box<dyn Error + 'static>);
+
+impl BoxedError {
+    /// Returns a reference to the boxed error.
+    pub fn inner(&self) -> &(dyn Error + 'static) {
+        &*self.0
+    }
+
+    /// Unwraps the boxed error.
+    pub fn into_inner(self) -> Box<dyn Error + 'static> {
+        self.0
+    }
+}
+
+impl fmt::Debug for BoxedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for BoxedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for BoxedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+
+    fn backtrace(&self) -> Option<&Backtrace> {
+        self.0.backtrace()
+    }
+}
+
+/// A structured description of a single error in a `Report`'s chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportEntry {
+    /// How far down the chain the error is, with the wrapped error at depth 0.
+    pub depth: usize,
+    /// The rendered message of the error.
+    pub message: String,
+    /// The name of the error's type, when it is known.
+    pub type_name: Option<String>,
+}
+
+/// The parts of a `Report`, rendered separately from each other, as returned by `Report::render`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedReport {
+    /// The rendered message of the wrapped error.
+    pub message: String,
+    /// The rendered message of each cause of the wrapped error, in the order they are reported.
+    pub causes: Vec<String>,
+    /// The rendered backtrace of the report, if there is one.
+    pub backtrace: Option<String>,
+}
+
+/// A set of independent errors reported together, such as the failures from validating several
+/// fields.
+///
+/// Unlike the sources of an error, the errors in a group aren't related to each other. Each one is
+/// rendered as its own numbered report, with its own chain of causes, and the reports are
+/// separated by blank lines.
+///
+/// The `Limits` of a group bound both the group and each report in it: `max_children` and
+/// `max_bytes` apply to the group as a whole, while the other limits apply to every report.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// use trial_and_error::error_reporter::{Limits, ReportGroup};
+/// #
+/// # #[derive(Debug)]
+/// # struct Layer(&'static str, Option<Box<Layer>>);
+/// #
+/// # impl fmt::Display for Layer {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         f.write_str(self.0)
+/// #     }
+/// # }
+/// #
+/// # impl Error for Layer {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         self.1.as_deref().map(|e| e as _)
+/// #     }
+/// # }
+/// let errors = || {
+///     vec![
+///         Layer("invalid name", None),
+///         Layer("invalid age", Some(Box::new(Layer("number too large", None)))),
+///         Layer("invalid email", None),
+///     ]
+/// };
+///
+/// let group: ReportGroup = errors().into_iter().collect();
+/// assert_eq!(
+///     group.to_string(),
+///     "0: invalid name\n\n1: invalid age: number too large\n\n2: invalid email",
+/// );
+///
+/// let group: ReportGroup = errors().into_iter().collect();
+/// assert_eq!(
+///     group.pretty().to_string(),
+///     "0: invalid name\n\n\
+///      1: invalid age\n\n   \
+///         Caused by:\n       \
+///             number too large\n\n\
+///      2: invalid email",
+/// );
+///
+/// let limits = Limits {
+///     max_children: Some(2),
+///     ..Limits::default()
+/// };
+/// let group: ReportGroup = errors().into_iter().collect();
+/// assert_eq!(
+///     group.limits(limits).to_string(),
+///     "0: invalid name\n\n1: invalid age: number too large\n\n... and 1 more",
+/// );
+///
+/// let limits = Limits {
+///     max_bytes: Some(24),
+///     ..limits
+/// };
+/// let group: ReportGroup = errors().into_iter().collect();
+/// assert_eq!(group.limits(limits).to_string(), "0: invalid name\n\n1: i...");
+/// ```
+#[derive(Debug)]
+pub struct ReportGroup {
+    errors: Vec<Box<dyn Error>>,
+    pretty: bool,
+    limits: Limits,
+    wrap_at: Option<usize>,
+}
+
+impl ReportGroup {
+    /// Create a new `ReportGroup` from a set of independent errors.
+    pub fn new(errors: Vec<Box<dyn Error>>) -> Self {
+        ReportGroup {
+            errors,
+            pretty: false,
+            limits: Limits::default(),
+            wrap_at: None,
+        }
+    }
+
+    /// Enable pretty-printing the report of every error in the group.
+    pub fn pretty(mut self) -> Self {
+        self.pretty = true;
+        self
+    }
+
+    /// Bound how much of the group, and of each report in it, is rendered.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Wrap the single-line report of each error in the group at `column`, like `Report::wrap_at`.
+    ///
+    /// The column includes the number in front of each report, and the continuation lines of a
+    /// report are indented to line up under its first line, followed by the usual two spaces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use trial_and_error::error_reporter::ReportGroup;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let errors = vec![
+    ///     Layer("invalid name", None),
+    ///     Layer("could not validate age", Some(Box::new(Layer("number too large", None)))),
+    /// ];
+    /// let group: ReportGroup = errors.into_iter().collect();
+    /// let group = group.wrap_at(40).to_string();
+    ///
+    /// assert_eq!(
+    ///     group,
+    ///     "0: invalid name\n\n\
+    ///      1: could not validate age:\n     \
+    ///           number too large",
+    /// );
+    /// assert!(group.lines().all(|line| line.len() <= 40));
+    /// ```
+    pub fn wrap_at(mut self, column: usize) -> Self {
+        self.wrap_at = Some(column);
+        self
+    }
+
+    /// Format every error in the group that fits within `max_children`, without `max_bytes`.
+    fn fmt_group(&self, f: &mut dyn Write) -> fmt::Result {
+        let format = if self.pretty {
+            ReportFormat::MultiLine
+        } else {
+            ReportFormat::SingleLine
+        };
+        // `max_bytes` bounds the whole group instead
+        let limits = Limits {
+            max_bytes: None,
+            ..self.limits
+        };
+
+        let total = self.errors.len();
+        let shown = self
+            .limits
+            .max_children
+            .map_or(total, |max_children| total.min(max_children));
+        let width = decimal_digits(shown.saturating_sub(1));
+
+        for (ind, error) in self.errors.iter().take(shown).enumerate() {
+            if ind > 0 {
+                f.write_str("\n\n")?;
+            }
+
+            let format_ind = Format::Numbered {
+                ind,
+                width,
+                color: false,
+            };
+            let mut report = Report::with_options(&**error, ReportOptions::default())
+                .format(format)
+                .limits(limits);
+            // The number in front of the report takes up the start of every line
+            if let Some(column) = self.wrap_at {
+                report = report.wrap_at(column.saturating_sub(width + 2));
+            }
+            report.write_to(&mut Indented::new(f, format_ind))?;
+        }
+
+        if shown < total {
+            if shown > 0 {
+                f.write_str("\n\n")?;
+            }
+            write!(f, "... and {} more", total - shown)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E> FromIterator<E> for ReportGroup
+where
+    E: Error + 'static,
+{
+    fn from_iter<I>(errors: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let errors = errors
+            .into_iter()
+            .map(|error| Box::new(error) as Box<dyn Error>)
+            .collect();
+        ReportGroup::new(errors)
+    }
+}
+
+impl fmt::Display for ReportGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.limits.max_bytes {
+            Some(max_bytes) => {
+                let mut group = String::new();
+                self.fmt_group(&mut group)?;
+                truncate_with_marker(&mut group, max_bytes);
+                f.write_str(&group)
+            }
+            None => self.fmt_group(f),
+        }
+    }
+}
+
+impl<E> Report<E>
+where
+    E: Error,
+{
+    /// Create a new `Report` from an input error.
+    ///
+    /// The report starts out with the options set by `set_default_options`, if any.
+    ///
+    /// References to errors implement `Error` as well, so a report can also be created straight
+    /// from a `&dyn Error`, without knowing the concrete type of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// fn log(error: &dyn Error) -> String {
+    ///     Report::new(error).pretty().to_string()
+    /// }
+    ///
+    /// let error = SuperError { side: SuperErrorSidekick };
+    /// assert_eq!(
+    ///     log(&error),
+    ///     "SuperError is here!\n\nCaused by:\n    SuperErrorSidekick is here!",
+    /// );
+    /// ```
+    pub fn new(source: E) -> Report<E> {
+        let options = DEFAULT_OPTIONS.get().copied().unwrap_or_default();
+        Report::with_options(source, options)
+    }
+
+    /// Create a new `Report` from an input error, starting out with `options`.
+    ///
+    /// Unlike `Report::new`, this ignores the default options set by `set_default_options`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// use trial_and_error::error_reporter::{ReportFormat, ReportOptions};
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let mut options = ReportOptions::default();
+    /// options.format = ReportFormat::MultiLine;
+    ///
+    /// let errors = vec![
+    ///     Layer("first", None),
+    ///     Layer("second", Some(Box::new(Layer("root", None)))),
+    /// ];
+    /// let reports: Vec<_> = errors
+    ///     .into_iter()
+    ///     .map(|error| Report::with_options(error, options).to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(reports, ["first", "second\n\nCaused by:\n    root"]);
+    /// ```
+    pub fn with_options(source: E, options: ReportOptions) -> Report<E> {
+        Report {
+            source,
+            show_backtrace: options.show_backtrace,
+            format: options.format,
+            env_context: false,
+            env_context_version: None,
+            alternate_inner: false,
+            thread: None,
+            redactions: Vec::new(),
+            decorator: None,
+            format_each: None,
+            static_source: None,
+            prose: false,
+            limits: Limits::default(),
+            indent_width: DEFAULT_INDENT_WIDTH,
+            backtrace: None,
+            supplied_backtrace: None,
+            color: false,
+            cause_header: "Caused by:",
+            dedup: false,
+            separator: ": ",
+            all_backtraces: false,
+            compact_numbering: false,
+            debug_chain: false,
+            wrap_at: None,
+            backtrace_filter: None,
+            skip_redundant: false,
+            line_prefix: None,
+            footer: None,
+            compact_spacing: false,
+            reverse: false,
+            help: None,
+            indent_all: false,
+            base_indent: 0,
+            backtrace_hint: false,
+            catch_panics: false,
+        }
+    }
+
+    /// Create a new `Report` that borrows `error` instead of taking ownership of it.
+    ///
+    /// This is the same as `Report::new(&error)`, but it leaves no room for type inference to
+    /// pick a `Report<E>` through the `From` impl in generic code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let error = SuperError { side: SuperErrorSidekick };
+    ///
+    /// let report = Report::borrow(&error);
+    /// assert_eq!(report.to_string(), "SuperError is here!: SuperErrorSidekick is here!");
+    /// assert_eq!(report.to_string(), "SuperError is here!: SuperErrorSidekick is here!");
+    ///
+    /// // The error is still around after the report is dropped
+    /// drop(report);
+    /// assert_eq!(error.to_string(), "SuperError is here!");
+    /// ```
+    pub fn borrow(error: &E) -> Report<&E> {
+        Report::new(error)
+    }
+    
+    /// Enable pretty-printing the report.
+    ///
+    /// This is shorthand for `format(ReportFormat::MultiLine)`. Trailing newlines in the messages
+    /// of the errors are left out, so they don't leave blank lines in the report, and so are
+    /// spaces at the end of each line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let root = Layer("root\ncontinued\n\n", None);
+    /// let middle = Layer("middle\n", Some(Box::new(root)));
+    /// let report = Report::new(Layer("top  \n", Some(Box::new(middle)))).pretty();
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "top\n\nCaused by:\n   0: middle\n   1: root\n      continued",
+    /// );
+    ///
+    /// let report = Report::new(Layer("rude\n  display   \n \n", None)).pretty();
+    /// assert_eq!(report.to_string(), "rude\n  display");
+    /// ```
+    pub fn pretty(self) -> Self {
+        self.format(ReportFormat::MultiLine)
+    }
+
+    /// Set the format the report is rendered in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// use trial_and_error::error_reporter::ReportFormat;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = || Report::new(SuperError { side: SuperErrorSidekick });
+    ///
+    /// assert_eq!(
+    ///     report().format(ReportFormat::MultiLine).to_string(),
+    ///     "SuperError is here!\n\nCaused by:\n    SuperErrorSidekick is here!",
+    /// );
+    /// assert_eq!(
+    ///     report().pretty().format(ReportFormat::SingleLine).to_string(),
+    ///     "SuperError is here!: SuperErrorSidekick is here!",
+    /// );
+    /// ```
+    pub fn format(mut self, format: ReportFormat) -> Self {
+        self.format = format;
+        self
+    }
+    
+    /// Enable showing a backtrace for the report.
+    pub fn show_backtrace(mut self) -> Self {
+        self.show_backtrace = true;
+        self
+    }
+
+    /// Enable appending details about the environment the report was produced in.
+    ///
+    /// The details include the operating system and architecture the program was compiled for,
+    /// plus the application version if one was supplied via `env_context_version`. These are
+    /// included in the pretty format as a trailing block, and in the single line format as a
+    /// parenthesized suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// use std::env::consts::{ARCH, OS};
+    ///
+    /// let report = Report::new(SuperError)
+    ///     .env_context(true)
+    ///     .env_context_version("1.2.3");
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     format!("SuperError is here! (os: {}, arch: {}, version: 1.2.3)", OS, ARCH),
+    /// );
+    /// assert_eq!(
+    ///     report.pretty().to_string(),
+    ///     format!(
+    ///         "SuperError is here!\n\n\
+    ///          Environment:\n    \
+    ///              os: {}\n    \
+    ///              arch: {}\n    \
+    ///              version: 1.2.3",
+    ///         OS, ARCH,
+    ///     ),
+    /// );
+    /// ```
+    pub fn env_context(mut self, env_context: bool) -> Self {
+        self.env_context = env_context;
+        self
+    }
+
+    /// Set the application version included when `env_context` is enabled.
+    ///
+    /// The crate has no way of knowing the version of the application using it, so this is
+    /// typically set to `env!("CARGO_PKG_VERSION")` by the caller.
+    pub fn env_context_version(mut self, version: &'static str) -> Self {
+        self.env_context_version = Some(version);
+        self
+    }
+
+    /// Render each error in the chain with its alternate `Display` format, `{:#}`.
+    ///
+    /// This lets errors that provide an expanded alternate form opt into it through the report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// #[derive(Debug)]
+    /// struct ParseError;
+    ///
+    /// impl fmt::Display for ParseError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         if f.alternate() {
+    ///             write!(f, "invalid digit\nexpected a number between 0 and 9")
+    ///         } else {
+    ///             write!(f, "invalid digit")
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl Error for ParseError {}
+    ///
+    /// # #[derive(Debug)]
+    /// # struct ConfigError(ParseError);
+    /// #
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "failed to read config")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for ConfigError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// let report = Report::new(ConfigError(ParseError)).pretty();
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "failed to read config\n\nCaused by:\n    invalid digit",
+    /// );
+    ///
+    /// let report = report.alternate_inner(true);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "failed to read config\n\n\
+    ///      Caused by:\n    \
+    ///          invalid digit\n    \
+    ///          expected a number between 0 and 9",
+    /// );
+    /// ```
+    pub fn alternate_inner(mut self, alternate_inner: bool) -> Self {
+        self.alternate_inner = alternate_inner;
+        self
+    }
+
+    /// Redact every occurrence of the given secrets from the rendered report.
+    ///
+    /// Redaction is applied as a final pass over the complete output, including the backtrace, and
+    /// replaces each secret with `***`. Calling this multiple times adds to the set of secrets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(String, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(&self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let token = "hunter2";
+    /// let root = Layer(format!("server rejected token {}", token), None);
+    /// let error = Layer(format!("failed to log in with {}", token), Some(Box::new(root)));
+    ///
+    /// let report = Report::new(error).redact(&[token]);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "failed to log in with ***: server rejected token ***",
+    /// );
+    /// ```
+    pub fn redact<S>(mut self, secrets: &[S]) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let secrets = secrets
+            .iter()
+            .map(AsRef::as_ref)
+            // An empty secret would match between every character
+            .filter(|secret| !secret.is_empty())
+            .map(String::from);
+        self.redactions.extend(secrets);
+        self
+    }
+
+    /// Decorate each error in the chain before it is rendered.
+    ///
+    /// The decorator is given each error in turn and returns the value that is displayed in its
+    /// place, so it can add prefixes, coloring, or substitute a different message entirely. The
+    /// decorated output is still indented and numbered like any other error.
+    ///
+    /// Each error is given to the decorator as a `'static` trait object, so that it can be
+    /// downcast to its concrete type. Reports made by `by_ref` and `context` render their own
+    /// wrapped error without the decorator, since its type isn't known to be `'static` there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use std::num::ParseIntError;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct ConfigError(ParseIntError);
+    /// #
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("invalid config")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for ConfigError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// let error = ConfigError("forty two".parse::<u16>().unwrap_err());
+    /// let report = Report::new(error).pretty().decorate(|error| {
+    ///     match error.downcast_ref::<ParseIntError>() {
+    ///         Some(error) => Box::new(format!("not a number ({})", error)),
+    ///         None => Box::new(error.to_string().to_uppercase()),
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "INVALID CONFIG\n\nCaused by:\n    not a number (invalid digit found in string)",
+    /// );
+    /// ```
+    pub fn decorate<F>(mut self, decorator: F) -> Self
+    where
+        E: 'static,
+        F: for<'a> Fn(&'a (dyn Error + 'static)) -> Box<dyn fmt::Display + 'a>,
+        F: Send + Sync + 'static,
+    {
+        self.decorator = Some(Arc::new(decorator));
+        self.static_source = Some(as_static::<E>);
+        self
+    }
+
+    /// Format each error in the chain with `format` instead of its `Display` impl.
+    ///
+    /// This is a lower level alternative to `decorate` that writes straight to the formatter, and
+    /// it takes precedence over any decorator that is set. The formatter has the alternate flag
+    /// set when the `alternate_inner` option is enabled. Like the decorator, `format` is given
+    /// each error as a `'static` trait object that it can downcast.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use std::num::ParseIntError;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct ConfigError(ParseIntError);
+    /// #
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("invalid config")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for ConfigError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// let error = ConfigError("".parse::<u16>().unwrap_err());
+    /// let report = Report::new(error).format_each(Box::new(|error, f| {
+    ///     match error.downcast_ref::<ParseIntError>() {
+    ///         Some(error) => write!(f, "bad number ({:?})", error.kind()),
+    ///         None => write!(f, "{}", error.to_string().to_uppercase()),
+    ///     }
+    /// }));
+    ///
+    /// assert_eq!(report.to_string(), "INVALID CONFIG: bad number (Empty)");
+    /// ```
+    pub fn format_each(
+        mut self,
+        format: Box<
+            dyn Fn(&(dyn Error + 'static), &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync,
+        >,
+    ) -> Self
+    where
+        E: 'static,
+    {
+        self.format_each = Some(Arc::from(format));
+        self.static_source = Some(as_static::<E>);
+        self
+    }
+
+    /// Render each error in the chain with its `Debug` impl instead of `Display`.
+    ///
+    /// This applies to the wrapped error and every cause, in both formats, and takes precedence
+    /// over `decorate` and `format_each`. With `alternate_inner` enabled the errors are rendered
+    /// with `{:#?}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #[derive(Debug)]
+    /// struct NotFound {
+    ///     path: &'static str,
+    /// }
+    ///
+    /// impl fmt::Display for NotFound {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "file not found")
+    ///     }
+    /// }
+    /// #
+    /// # impl Error for NotFound {}
+    ///
+    /// #[derive(Debug)]
+    /// struct LoadFailed(NotFound);
+    /// #
+    /// # impl fmt::Display for LoadFailed {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "failed to load")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for LoadFailed {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    ///
+    /// let report = || Report::new(LoadFailed(NotFound { path: "config.toml" }));
+    ///
+    /// assert_eq!(report().to_string(), "failed to load: file not found");
+    /// assert_eq!(
+    ///     report().debug_chain(true).to_string(),
+    ///     r#"LoadFailed(NotFound { path: "config.toml" }): NotFound { path: "config.toml" }"#,
+    /// );
+    /// ```
+    pub fn debug_chain(mut self, debug_chain: bool) -> Self {
+        self.debug_chain = debug_chain;
+        self
+    }
+
+    /// Phrase the single line format as a sentence, joining causes with `because`.
+    ///
+    /// The first letter of each cause is lowercased so the chain reads naturally, unless the cause
+    /// starts with an acronym. The multi-line format is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let root = Layer("Permission was denied", None);
+    /// let middle = Layer("The port was in use", Some(Box::new(root)));
+    /// let error = Layer("Failed to start server", Some(Box::new(middle)));
+    /// let report = Report::new(error).prose(true);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "Failed to start server because the port was in use because permission was denied",
+    /// );
+    /// ```
+    pub fn prose(mut self, prose: bool) -> Self {
+        self.prose = prose;
+        self
+    }
+
+    /// Bound how much of the error chain is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// # use trial_and_error::error_reporter::Limits;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(String, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(&self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let error = (1..=10).rev().fold(None, |source, depth| {
+    ///     Some(Layer(format!("layer {}", depth), source.map(Box::new)))
+    /// });
+    /// let limits = Limits {
+    ///     max_depth: Some(3),
+    ///     ..Limits::default()
+    /// };
+    /// let report = Report::new(error.unwrap()).limits(limits);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "layer 1: layer 2: layer 3: layer 4: ...",
+    /// );
+    ///
+    /// let report = report.pretty();
+    /// assert!(report.to_string().ends_with("layer 4\n    ... and 6 more"));
+    ///
+    /// let limits = Limits {
+    ///     max_bytes: Some(32),
+    ///     ..limits
+    /// };
+    /// let report = report.limits(limits);
+    /// assert_eq!(report.to_string(), "layer 1\n\nCaused by:\n   0: lay...");
+    /// assert_eq!(report.to_string().len(), 32);
+    /// ```
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Limit the number of causes rendered below the wrapped error.
+    ///
+    /// This is shorthand for setting `Limits::max_depth` through `limits`. In the multi-line format
+    /// the causes left out are summarized by a final `... and N more` line, while the single line
+    /// format ends with `...`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(String, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(&self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// // An error with a chain of 10 causes below it
+    /// let error = (0..=10).rev().fold(None, |source, depth| {
+    ///     Some(Layer(format!("layer {}", depth), source.map(Box::new)))
+    /// });
+    /// let report = Report::new(error.unwrap()).max_depth(Some(3));
+    ///
+    /// assert_eq!(report.to_string(), "layer 0: layer 1: layer 2: layer 3: ...");
+    /// assert_eq!(
+    ///     report.pretty().to_string(),
+    ///     "layer 0\n\n\
+    ///      Caused by:\n   \
+    ///         0: layer 1\n   \
+    ///         1: layer 2\n   \
+    ///         2: layer 3\n    \
+    ///          ... and 7 more",
+    /// );
+    /// ```
+    pub fn max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.limits.max_depth = max_depth;
+        self
+    }
+
+    /// Limit the message of each error in the chain to `max_chars` characters.
+    ///
+    /// This is shorthand for setting `Limits::max_message_chars` through `limits`, and keeps a
+    /// single runaway message from taking over the report in either format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(String, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(&self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let output = "é".repeat(1000);
+    /// let root = Layer(format!("subprocess failed: {}", output), None);
+    /// let report = Report::new(Layer(String::from("build failed"), Some(Box::new(root))))
+    ///     .truncate_message(20);
+    ///
+    /// assert_eq!(report.to_string(), "build failed: subprocess failed...");
+    /// assert_eq!(
+    ///     report.pretty().to_string(),
+    ///     "build failed\n\nCaused by:\n    subprocess failed...",
+    /// );
+    ///
+    /// let root = Layer(output, None);
+    /// let report = Report::new(root).truncate_message(20);
+    /// assert_eq!(report.to_string(), format!("{}...", "é".repeat(17)));
+    /// ```
+    pub fn truncate_message(mut self, max_chars: usize) -> Self {
+        self.limits.max_message_chars = Some(max_chars);
+        self
+    }
+
+    /// Set the number of spaces causes are indented by in the multi-line format.
+    ///
+    /// Defaults to 4. When causes are numbered, the numbers are right aligned within this width,
+    /// and continuation lines are indented to line up with the text after the number. If the
+    /// largest number shown doesn't fit, every number is widened to fit it instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = || {
+    ///     let root = Layer("root\nspanning two lines", None);
+    ///     let middle = Layer("middle", Some(Box::new(root)));
+    ///     Report::new(Layer("top", Some(Box::new(middle)))).pretty()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report().indent_width(2).to_string(),
+    ///     "top\n\n\
+    ///      Caused by:\n\
+    ///      \x200: middle\n\
+    ///      \x201: root\n\
+    ///      \x20   spanning two lines",
+    /// );
+    /// assert_eq!(
+    ///     report().indent_width(8).to_string(),
+    ///     "top\n\n\
+    ///      Caused by:\n       \
+    ///             0: middle\n       \
+    ///             1: root\n          \
+    ///                spanning two lines",
+    /// );
+    ///
+    /// let root = Layer("root\nspanning two lines", None);
+    /// let chain = (0..1000).fold(root, |cause, _| Layer("wrapper", Some(Box::new(cause))));
+    /// let report = Report::new(Layer("top", Some(Box::new(chain)))).pretty();
+    ///
+    /// for report in [report.by_ref(), report.by_ref().indent_width(2)] {
+    ///     let rendered = report.to_string();
+    ///     let last_lines: Vec<&str> = rendered.lines().rev().take(3).collect();
+    ///     assert_eq!(
+    ///         last_lines,
+    ///         ["      spanning two lines", "1000: root", " 999: wrapper"],
+    ///     );
+    /// }
+    /// ```
+    pub fn indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Render the report into `w`, using the same format as `Display`.
+    ///
+    /// This allows rendering into a preallocated buffer or a custom writer without going through
+    /// `to_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = Report::new(SuperError).pretty();
+    ///
+    /// let mut buffer = String::with_capacity(64);
+    /// report.write_to(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(buffer, report.to_string());
+    /// ```
+    pub fn write_to<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: Write,
+    {
+        self.fmt_report(self.format, self.top_link(), w)
+    }
+
+    /// Print the report to stdout, followed by a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = Report::new(SuperError);
+    ///
+    /// // Prints `SuperError is here!` to stdout
+    /// report.print();
+    /// // Prints `SuperError is here!` to stderr
+    /// report.eprint();
+    /// ```
+    pub fn print(&self) {
+        println!("{}", self);
+    }
+
+    /// Print the report to stderr, followed by a newline.
+    pub fn eprint(&self) {
+        eprintln!("{}", self);
+    }
+
+    /// Render the report in the multi-line format, regardless of the format it was configured
+    /// with.
+    ///
+    /// Together with `to_string_compact` this lets a test check both renderings of one report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = Report::new(SuperError { side: SuperErrorSidekick })
+    ///     .separator(" -> ")
+    ///     .cause_header("Because:");
+    ///
+    /// assert_eq!(
+    ///     report.to_string_pretty(),
+    ///     "SuperError is here!\n\nBecause:\n    SuperErrorSidekick is here!",
+    /// );
+    /// assert_eq!(
+    ///     report.to_string_compact(),
+    ///     "SuperError is here! -> SuperErrorSidekick is here!",
+    /// );
+    /// assert_eq!(report.to_string(), report.to_string_compact());
+    /// ```
+    pub fn to_string_pretty(&self) -> String {
+        self.render_to_string(ReportFormat::MultiLine)
+    }
+
+    /// Render the report in the single-line format, regardless of the format it was configured
+    /// with.
+    pub fn to_string_compact(&self) -> String {
+        self.render_to_string(ReportFormat::SingleLine)
+    }
+
+    /// Render the report into an `io::Write` sink, such as a file or a socket.
+    ///
+    /// The report is written straight through to `w`, the same way as `write_to`, without first
+    /// being rendered into a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = Report::new(SuperError).pretty();
+    ///
+    /// let mut log = Vec::new();
+    /// report.write_io(&mut log).unwrap();
+    ///
+    /// assert_eq!(log, report.to_string().as_bytes());
+    /// ```
+    pub fn write_io<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        write!(w, "{}", self)
+    }
+
+    /// Render the report into an `io::Write` sink in the multi-line format, regardless of the
+    /// format it was configured with.
+    ///
+    /// Like `write_io`, the report is written straight through to `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = Report::new(SuperError { side: SuperErrorSidekick });
+    ///
+    /// let mut pretty = Vec::new();
+    /// report.to_writer_pretty(&mut pretty).unwrap();
+    /// assert_eq!(pretty, b"SuperError is here!\n\nCaused by:\n    SuperErrorSidekick is here!");
+    ///
+    /// let mut compact = Vec::new();
+    /// report.pretty().to_writer_compact(&mut compact).unwrap();
+    /// assert_eq!(compact, b"SuperError is here!: SuperErrorSidekick is here!");
+    /// ```
+    pub fn to_writer_pretty<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let report = InFormat {
+            report: self,
+            format: ReportFormat::MultiLine,
+        };
+        write!(w, "{}", report)
+    }
+
+    /// Render the report into an `io::Write` sink in the single-line format, regardless of the
+    /// format it was configured with.
+    pub fn to_writer_compact<W>(&self, w: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let report = InFormat {
+            report: self,
+            format: ReportFormat::SingleLine,
+        };
+        write!(w, "{}", report)
+    }
+
+    /// Render the report into `w`, skipping the first `common_depth` errors of the chain.
+    ///
+    /// This is meant for repeatedly logging errors that share the same outer context, where only
+    /// the innermost causes change between reports. The first remaining error is rendered as the
+    /// top of the report, preceded by a note saying how many errors were skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// #
+    /// # fn chain(msgs: &[&'static str]) -> Layer {
+    /// #     let mut msgs = msgs.iter().rev();
+    /// #     let mut error = Layer(msgs.next().unwrap(), None);
+    /// #     for msg in msgs {
+    /// #         error = Layer(msg, Some(Box::new(error)));
+    /// #     }
+    /// #     error
+    /// # }
+    /// let error = chain(&["retrying request", "request failed", "timed out", "no route"]);
+    /// let report = Report::new(error);
+    ///
+    /// let mut suffix = String::new();
+    /// report.render_suffix_from(2, &mut suffix).unwrap();
+    /// assert_eq!(suffix, "[2 errors omitted] timed out: no route");
+    ///
+    /// let mut suffix = String::new();
+    /// report.pretty().render_suffix_from(2, &mut suffix).unwrap();
+    /// assert_eq!(suffix, "[2 errors omitted]\ntimed out\n\nCaused by:\n    no route");
+    /// ```
+    pub fn render_suffix_from<W>(&self, common_depth: usize, w: &mut W) -> fmt::Result
+    where
+        W: Write,
+    {
+        if common_depth == 0 {
+            return self.fmt_report(self.format, self.top_link(), w);
+        }
+
+        let mut remaining = self.errors().skip(common_depth);
+        let skipped = common_depth.min(self.errors().count());
+        let plural = if skipped == 1 { "" } else { "s" };
+
+        write!(w, "[{} error{} omitted]", skipped, plural)?;
+
+        match remaining.next() {
+            Some(error) => {
+                let separator = match self.format {
+                    ReportFormat::SingleLine => ' ',
+                    ReportFormat::MultiLine => '\n',
+                };
+                w.write_char(separator)?;
+                self.fmt_report(self.format, error, w)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Capture a backtrace to show when the wrapped error doesn't provide one of its own.
+    ///
+    /// The backtrace is captured when this method is called, so it should be called as close to
+    /// where the error originated as possible. A backtrace provided by the error itself still takes
+    /// precedence. Capturing respects the `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment
+    /// variables, and when capturing is disabled nothing is printed in place of the backtrace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// std::env::set_var("RUST_BACKTRACE", "1");
+    ///
+    /// let report = Report::new(SuperError)
+    ///     .capture_backtrace()
+    ///     .pretty()
+    ///     .show_backtrace();
+    ///
+    /// assert!(report.to_string().starts_with("SuperError is here!\n\nStack backtrace:\n"));
+    /// ```
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// std::env::set_var("RUST_BACKTRACE", "0");
+    ///
+    /// let report = Report::new(SuperError)
+    ///     .capture_backtrace()
+    ///     .pretty()
+    ///     .show_backtrace();
+    ///
+    /// assert_eq!(report.to_string(), "SuperError is here!");
+    /// ```
+    pub fn capture_backtrace(mut self) -> Self {
+        self.backtrace = Some(Arc::new(Backtrace::capture()));
+        self
+    }
+
+    /// Show `backtrace` in place of the error's own backtrace.
+    ///
+    /// This is useful when the backtrace that matters was captured somewhere else, such as where
+    /// the error crossed a thread boundary. Like any other backtrace it is only shown if it was
+    /// actually captured and `show_backtrace` is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::backtrace::Backtrace;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError(Option<Backtrace>);
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn backtrace(&self) -> Option<&Backtrace> {
+    /// #         self.0.as_ref()
+    /// #     }
+    /// # }
+    /// let report = Report::new(SuperError(Some(Backtrace::force_capture())))
+    ///     .with_backtrace(Backtrace::force_capture())
+    ///     .pretty()
+    ///     .show_backtrace();
+    ///
+    /// assert!(report.to_string().starts_with("SuperError is here!\n\nStack backtrace:\n"));
+    /// // The supplied backtrace is shown rather than the error's own
+    /// let inner = report.inner().backtrace().unwrap();
+    /// assert!(!std::ptr::eq(report.backtrace().unwrap(), inner));
+    ///
+    /// let report = Report::new(SuperError(None))
+    ///     .with_backtrace(Backtrace::disabled())
+    ///     .pretty()
+    ///     .show_backtrace();
+    ///
+    /// assert_eq!(report.to_string(), "SuperError is here!");
+    /// ```
+    pub fn with_backtrace(mut self, backtrace: Backtrace) -> Self {
+        self.supplied_backtrace = Some(Arc::new(backtrace));
+        self
+    }
+
+    /// Also show the backtraces of the causes when `show_backtrace` is enabled.
+    ///
+    /// Each cause's backtrace is shown below it in the `Caused by:` section of the multi-line
+    /// format. Causes that don't have a backtrace are shown as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::backtrace::Backtrace;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Traced(&'static str, Backtrace, Option<Box<Traced>>);
+    /// #
+    /// # impl fmt::Display for Traced {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Traced {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.2.as_deref().map(|e| e as _)
+    /// #     }
+    /// #
+    /// #     fn backtrace(&self) -> Option<&Backtrace> {
+    /// #         Some(&self.1)
+    /// #     }
+    /// # }
+    /// fn root() -> Traced {
+    ///     Traced("root", Backtrace::force_capture(), None)
+    /// }
+    ///
+    /// let top = Traced("top", Backtrace::force_capture(), Some(Box::new(root())));
+    /// let rendered = Report::new(top)
+    ///     .pretty()
+    ///     .show_backtrace()
+    ///     .all_backtraces(true)
+    ///     .to_string();
+    ///
+    /// assert!(rendered.starts_with("top\n\nCaused by:\n    root\n\n    Stack backtrace:\n"));
+    /// assert_eq!(rendered.matches("Stack backtrace:").count(), 2);
+    /// // Only the root's backtrace was captured in `root`
+    /// let (causes, top_backtrace) = rendered.rsplit_once("\n\nStack backtrace:\n").unwrap();
+    /// assert!(causes.contains("::root\n"));
+    /// assert!(!top_backtrace.contains("::root\n"));
+    /// ```
+    pub fn all_backtraces(mut self, all_backtraces: bool) -> Self {
+        self.all_backtraces = all_backtraces;
+        self
+    }
+
+    /// Only show the lines of a backtrace for which `filter` returns `true`.
+    ///
+    /// This is useful for hiding frames from the standard library and the runtime. The filter is
+    /// given each line of the rendered backtrace in turn, so a frame's symbol and its source
+    /// location are filtered separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::backtrace::Backtrace;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = || {
+    ///     Report::new(SuperError)
+    ///         .with_backtrace(Backtrace::force_capture())
+    ///         .pretty()
+    ///         .show_backtrace()
+    /// };
+    ///
+    /// assert!(report().to_string().contains("std::rt"));
+    ///
+    /// let filtered = report()
+    ///     .backtrace_filter(Box::new(|line| !line.contains("std::rt")))
+    ///     .to_string();
+    /// assert!(filtered.starts_with("SuperError is here!\n\nStack backtrace:\n"));
+    /// assert!(!filtered.contains("std::rt"));
+    /// ```
+    pub fn backtrace_filter(mut self, filter: Box<dyn Fn(&str) -> bool + Send + Sync>) -> Self {
+        self.backtrace_filter = Some(Arc::from(filter));
+        self
+    }
+
+    /// Explain how to enable backtraces when `show_backtrace` is enabled but there is no captured
+    /// backtrace to show.
+    ///
+    /// The multi-line format then ends its backtrace block with
+    /// `Backtrace: unavailable (set RUST_BACKTRACE=1)`, instead of leaving it out or showing a
+    /// backtrace that wasn't captured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = || Report::new(SuperError).pretty().show_backtrace();
+    ///
+    /// assert_eq!(
+    ///     report().backtrace_hint(true).to_string(),
+    ///     "SuperError is here!\n\nBacktrace: unavailable (set RUST_BACKTRACE=1)",
+    /// );
+    /// assert_eq!(report().to_string(), "SuperError is here!");
+    /// ```
+    pub fn backtrace_hint(mut self, backtrace_hint: bool) -> Self {
+        self.backtrace_hint = backtrace_hint;
+        self
+    }
+
+    /// Catch panics from the errors in the chain while formatting the report.
+    ///
+    /// An error whose `Display` impl panics is shown as `<panic while formatting error>`, and the
+    /// rest of the chain is reported as usual. If `source` panics instead, the report ends with
+    /// the same marker, since there is no way to continue the chain. The panic hook still runs, so
+    /// the panic message is printed to stderr as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// #[derive(Debug)]
+    /// struct Buggy;
+    ///
+    /// impl fmt::Display for Buggy {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         f.write_str("buggy")
+    ///     }
+    /// }
+    ///
+    /// impl Error for Buggy {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         panic!("no source for you")
+    ///     }
+    /// }
+    ///
+    /// let report = Report::new(SuperError { side: SuperErrorSidekick }).catch_panics(true);
+    /// assert_eq!(report.to_string(), "SuperError is here!: SuperErrorSidekick is here!");
+    ///
+    /// let report = Report::new(Buggy).catch_panics(true);
+    /// assert_eq!(report.to_string(), "buggy: <panic while formatting error>");
+    /// assert_eq!(
+    ///     report.pretty().to_string(),
+    ///     "buggy\n<panic while formatting error>",
+    /// );
+    /// ```
+    pub fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    /// Style the multi-line format with ANSI escape codes.
+    ///
+    /// When enabled, the wrapped error's message is bold, the `Caused by:` header is dimmed, and
+    /// the indices of numbered causes are colored. This is off by default, in which case no escape
+    /// codes are emitted at all, so it should only be enabled when writing to a terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = || {
+    ///     let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    ///     Report::new(Layer("top", Some(Box::new(middle)))).pretty()
+    /// };
+    ///
+    /// assert!(!report().to_string().contains('\x1b'));
+    /// assert_eq!(
+    ///     report().color(true).to_string(),
+    ///     "\x1b[1mtop\x1b[0m\n\n\
+    ///      \x1b[2mCaused by:\x1b[0m\n\
+    ///      \x1b[36m   0:\x1b[0m middle\n\
+    ///      \x1b[36m   1:\x1b[0m root",
+    /// );
+    /// ```
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Only number the causes in the multi-line format when there are more than two of them.
+    ///
+    /// By default causes are numbered whenever there is more than one. With this option enabled,
+    /// a chain with two causes is indented without numbers instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// #
+    /// # fn chain(msgs: &[&'static str]) -> Layer {
+    /// #     let mut msgs = msgs.iter().rev();
+    /// #     let mut error = Layer(msgs.next().unwrap(), None);
+    /// #     for msg in msgs {
+    /// #         error = Layer(msg, Some(Box::new(error)));
+    /// #     }
+    /// #     error
+    /// # }
+    /// let report = |messages: &[&'static str]| {
+    ///     Report::new(chain(messages)).pretty().compact_numbering(true)
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report(&["top", "middle", "root"]).to_string(),
+    ///     "top\n\nCaused by:\n    middle\n    root",
+    /// );
+    /// assert_eq!(
+    ///     report(&["top", "upper", "lower", "root"]).to_string(),
+    ///     "top\n\nCaused by:\n   0: upper\n   1: lower\n   2: root",
+    /// );
+    /// ```
+    pub fn compact_numbering(mut self, compact_numbering: bool) -> Self {
+        self.compact_numbering = compact_numbering;
+        self
+    }
+
+    /// Leave out causes whose message is already at the end of the report in the single-line
+    /// format.
+    ///
+    /// Some errors include the message of their source in their own, which would otherwise show
+    /// up twice in a row. Only a whole message counts, one that follows a `: ` or the separator,
+    /// so a cause that merely matches the last few characters is still shown. The multi-line
+    /// format is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = || {
+    ///     let root = Layer("invalid digit", None);
+    ///     let parse = Layer("parse failed: invalid digit", Some(Box::new(root)));
+    ///     Report::new(Layer("could not load config", Some(Box::new(parse))))
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report().skip_redundant(true).to_string(),
+    ///     "could not load config: parse failed: invalid digit",
+    /// );
+    /// assert_eq!(
+    ///     report().to_string(),
+    ///     "could not load config: parse failed: invalid digit: invalid digit",
+    /// );
+    ///
+    /// let report = Report::new(Layer("parse 42", Some(Box::new(Layer("2", None)))));
+    /// assert_eq!(report.skip_redundant(true).to_string(), "parse 42: 2");
+    /// ```
+    pub fn skip_redundant(mut self, skip_redundant: bool) -> Self {
+        self.skip_redundant = skip_redundant;
+        self
+    }
+
+    /// Replace the `Caused by:` header that introduces the causes in the multi-line format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = || {
+    ///     Report::new(SuperError { side: SuperErrorSidekick }).cause_header("Because:")
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report().pretty().to_string(),
+    ///     "SuperError is here!\n\nBecause:\n    SuperErrorSidekick is here!",
+    /// );
+    /// assert_eq!(report().to_string(), "SuperError is here!: SuperErrorSidekick is here!");
+    /// ```
+    pub fn cause_header(mut self, header: &'static str) -> Self {
+        self.cause_header = header;
+        self
+    }
+
+    /// Collapse runs of consecutive causes that render identically into a single cause.
+    ///
+    /// A collapsed cause is followed by the number of causes it stands for, like `(x3)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = || {
+    ///     let root = Layer("disk full", None);
+    ///     let retried = (0..3).fold(root, |cause, _| {
+    ///         Layer("write failed", Some(Box::new(cause)))
+    ///     });
+    ///     Report::new(Layer("save failed", Some(Box::new(retried)))).dedup(true)
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report().pretty().to_string(),
+    ///     "save failed\n\nCaused by:\n   0: write failed (x3)\n   1: disk full",
+    /// );
+    /// assert_eq!(report().to_string(), "save failed: write failed (x3): disk full");
+    /// ```
+    pub fn dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Wrap the single-line format onto a new line before any cause that would extend past
+    /// `column`.
+    ///
+    /// Lines are only broken between errors, never within a message, and each continuation line
+    /// is indented by two spaces. A single message that is longer than `column` is left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// #
+    /// # fn chain(msgs: &[&'static str]) -> Layer {
+    /// #     let mut msgs = msgs.iter().rev();
+    /// #     let mut error = Layer(msgs.next().unwrap(), None);
+    /// #     for msg in msgs {
+    /// #         error = Layer(msg, Some(Box::new(error)));
+    /// #     }
+    /// #     error
+    /// # }
+    /// let error = chain(&[
+    ///     "failed to deploy service",
+    ///     "could not upload artifact",
+    ///     "connection reset",
+    ///     "broken pipe",
+    /// ]);
+    /// let report = Report::new(error).wrap_at(40);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "failed to deploy service:\n  \
+    ///      could not upload artifact:\n  \
+    ///      connection reset: broken pipe",
+    /// );
+    /// assert!(report.to_string().lines().all(|line| line.len() <= 40));
+    /// ```
+    pub fn wrap_at(mut self, column: usize) -> Self {
+        self.wrap_at = Some(column);
+        self
+    }
+
+    /// Set the string placed between errors in the single-line format, which defaults to `": "`.
+    ///
+    /// The separator is ignored when the `prose` option is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let root = Layer("unknown key: color", None);
+    /// let report = Report::new(Layer("config: invalid", Some(Box::new(root)))).separator(" -> ");
+    ///
+    /// assert_eq!(report.to_string(), "config: invalid -> unknown key: color");
+    /// ```
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Start every line of the report with `prefix`, ahead of any indentation.
+    ///
+    /// Blank lines get the prefix too, with its trailing whitespace removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// use trial_and_error::error_reporter::Limits;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let report = Report::new(Layer("top", Some(Box::new(middle))))
+    ///     .pretty()
+    ///     .line_prefix(String::from("| "));
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "| top\n|\n| Caused by:\n|    0: middle\n|    1: root",
+    /// );
+    ///
+    /// // The prefixes count towards `max_bytes`
+    /// let limits = Limits {
+    ///     max_bytes: Some(20),
+    ///     ..Limits::default()
+    /// };
+    /// let report = report.line_prefix(String::from(">>>> ")).limits(limits);
+    /// assert_eq!(report.to_string(), ">>>> top\n>>>>\n>>>...");
+    /// assert_eq!(report.to_string().len(), 20);
+    /// ```
+    pub fn line_prefix(mut self, prefix: String) -> Self {
+        self.line_prefix = Some(prefix);
+        self
+    }
+
+    /// Show `footer` in its own block at the end of the multi-line format, after the backtrace.
+    ///
+    /// The single-line format is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::backtrace::Backtrace;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let report = Report::new(SuperError)
+    ///     .with_backtrace(Backtrace::force_capture())
+    ///     .show_backtrace()
+    ///     .footer(String::from("Run with --verbose for more detail."));
+    ///
+    /// let pretty = report.to_string_pretty();
+    /// let backtrace = pretty.find("Stack backtrace:").unwrap();
+    /// let footer = pretty.find("\n\nRun with --verbose for more detail.").unwrap();
+    /// assert!(backtrace < footer);
+    /// assert!(pretty.ends_with("more detail."));
+    ///
+    /// assert_eq!(report.to_string_compact(), "SuperError is here!");
+    /// ```
+    pub fn footer(mut self, footer: String) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    /// Leave out the blank lines between the blocks of the multi-line format, such as the one
+    /// before the `Caused by:` header.
+    ///
+    /// The backtraces of the causes shown by `all_backtraces` keep their blank line, since they
+    /// are part of each cause's entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = || {
+    ///     let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    ///     Report::new(Layer("top", Some(Box::new(middle)))).pretty()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     report().compact_spacing(true).to_string(),
+    ///     "top\nCaused by:\n   0: middle\n   1: root",
+    /// );
+    /// assert_eq!(
+    ///     report().to_string(),
+    ///     "top\n\nCaused by:\n   0: middle\n   1: root",
+    /// );
+    /// ```
+    pub fn compact_spacing(mut self, compact_spacing: bool) -> Self {
+        self.compact_spacing = compact_spacing;
+        self
+    }
+
+    /// Start the multi-line format with the root cause, followed by the errors it was handled by,
+    /// walking outward to the wrapped error.
+    ///
+    /// The errors are introduced by a `While handling:` header instead of the cause header.
+    /// Repeated errors are collapsed with `dedup` as usual, and the `max_depth` limit keeps the
+    /// errors closest to the root cause. The single-line format is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let report = Report::new(Layer("top", Some(Box::new(middle))))
+    ///     .pretty()
+    ///     .reverse(true);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "root\n\nWhile handling:\n   0: middle\n   1: top",
+    /// );
+    /// assert_eq!(report.to_string_compact(), "top: middle: root");
+    ///
+    /// let inner = Layer("inner", Some(Box::new(Layer("root", None))));
+    /// let middle = Layer("middle", Some(Box::new(Layer("middle", Some(Box::new(inner))))));
+    /// let report = Report::new(Layer("top", Some(Box::new(middle))))
+    ///     .pretty()
+    ///     .reverse(true)
+    ///     .dedup(true);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "root\n\nWhile handling:\n   0: inner\n   1: middle (x2)\n   2: top",
+    /// );
+    /// assert_eq!(
+    ///     report.max_depth(Some(2)).to_string(),
+    ///     "root\n\nWhile handling:\n   0: inner\n   1: middle (x2)\n    ... and 1 more",
+    /// );
+    /// ```
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Show advice on how to resolve an error below it in the multi-line format.
+    ///
+    /// `help` is called for each error in the chain, and every `Some` message it returns is shown
+    /// as a `help: ...` line, indented along with the error's message. The single-line format is
+    /// unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::num::ParseIntError;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct ConfigError(ParseIntError);
+    /// #
+    /// # impl fmt::Display for ConfigError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("invalid config")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for ConfigError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// let error = ConfigError("x".parse::<u32>().unwrap_err());
+    /// let report = Report::new(error).pretty().help_with(Box::new(|error| {
+    ///     let message = error.to_string();
+    ///     let invalid = message.starts_with("invalid digit");
+    ///     invalid.then(|| String::from("`port` must be a whole number"))
+    /// }));
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "invalid config\n\n\
+    ///      Caused by:\n    \
+    ///          invalid digit found in string\n    \
+    ///          help: `port` must be a whole number",
+    /// );
+    /// ```
+    pub fn help_with(
+        mut self,
+        help: Box<dyn Fn(&dyn Error) -> Option<String> + Send + Sync>,
+    ) -> Self {
+        self.help = Some(Arc::from(help));
+        self
+    }
+
+    /// Indent the top error of the multi-line format the same way as its causes.
+    ///
+    /// This keeps the left edge of the report even when it is embedded in other output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = Report::new(Layer("top\nsecond line", Some(Box::new(Layer("root", None)))))
+    ///     .pretty()
+    ///     .indent_all(true);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "    top\n    second line\n\nCaused by:\n    root",
+    /// );
+    /// ```
+    pub fn indent_all(mut self, indent_all: bool) -> Self {
+        self.indent_all = indent_all;
+        self
+    }
+
+    /// Indent every line of the report by `base_indent` spaces, on top of the indentation of
+    /// each cause.
+    ///
+    /// This is meant for nesting the report of one error inside the output of another. Blank
+    /// lines are left empty, and the indentation comes after any `line_prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let report = Report::new(Layer("top", Some(Box::new(middle))))
+    ///     .pretty()
+    ///     .base_indent(4);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "    top\n\n    Caused by:\n       0: middle\n       1: root",
+    /// );
+    /// ```
+    pub fn base_indent(mut self, base_indent: usize) -> Self {
+        self.base_indent = base_indent;
+        self
+    }
+
+    /// Finish configuring the report.
+    ///
+    /// This does nothing, but can be used to mark the end of a chain of builder methods.
+    pub fn finish(self) -> Self {
+        self
+    }
+
+    /// Returns the current formatting options of the report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// use trial_and_error::error_reporter::ReportFormat;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {}
+    /// let options = Report::new(SuperError).options();
+    /// assert_eq!(options.format, ReportFormat::SingleLine);
+    /// assert!(!options.show_backtrace);
+    ///
+    /// let options = Report::new(SuperError).pretty().show_backtrace().finish().options();
+    /// assert_eq!(options.format, ReportFormat::MultiLine);
+    /// assert!(options.show_backtrace);
+    /// ```
+    pub fn options(&self) -> ReportOptions {
+        ReportOptions {
+            format: self.format,
+            show_backtrace: self.show_backtrace,
+        }
+    }
+
+    /// Returns a reference to the wrapped error.
+    pub fn inner(&self) -> &E {
+        &self.source
+    }
+
+    /// Returns the backtrace the report shows when `show_backtrace` is enabled, whether or not it
+    /// is.
+    ///
+    /// This is a backtrace supplied through `with_backtrace`, the wrapped error's own backtrace,
+    /// or the one taken by `capture_backtrace`, in that order. Backtraces that weren't captured
+    /// are skipped, except for the wrapped error's own. It is the same backtrace returned by the
+    /// `Error::backtrace` impl of the report, available without importing the trait.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(backtrace)]
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// use std::backtrace::{Backtrace, BacktraceStatus};
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError(Backtrace);
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn backtrace(&self) -> Option<&Backtrace> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// let report = Report::new(SuperError(Backtrace::force_capture()));
+    ///
+    /// let backtrace = report.backtrace().unwrap();
+    /// assert_eq!(backtrace.status(), BacktraceStatus::Captured);
+    /// assert!(std::ptr::eq(backtrace, &report.inner().0));
+    /// assert_eq!(report.to_string(), "SuperError is here!");
+    /// ```
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.find_backtrace(&self.source)
+    }
+
+    /// Returns a reference to the wrapped error, at the top of the chain.
+    ///
+    /// This is the same as `inner`, and is the counterpart to `root_cause`.
+    pub fn top(&self) -> &E {
+        &self.source
+    }
+
+    /// Returns the source of the wrapped error, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// #[derive(Debug)]
+    /// struct StatusError {
+    ///     status: u16,
+    /// }
+    ///
+    /// # impl fmt::Display for StatusError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "server responded with {}", self.status)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for StatusError {}
+    /// #
+    /// #[derive(Debug)]
+    /// struct RequestError {
+    ///     source: StatusError,
+    /// }
+    ///
+    /// # impl fmt::Display for RequestError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "request failed")
+    /// #     }
+    /// # }
+    /// #
+    /// impl Error for RequestError {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.source)
+    ///     }
+    /// }
+    ///
+    /// let report = Report::new(RequestError {
+    ///     source: StatusError { status: 503 },
+    /// });
+    ///
+    /// let status = report
+    ///     .source()
+    ///     .and_then(|source| source.downcast_ref::<StatusError>())
+    ///     .map(|error| error.status);
+    ///
+    /// assert_eq!(status, Some(503));
+    /// assert_eq!(report.inner().to_string(), "request failed");
+    /// ```
+    pub fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.source()
+    }
+
+    /// Create a report that borrows the wrapped error, with the same formatting options as this one.
+    ///
+    /// This allows rendering the same error multiple times with different options without moving
+    /// the error out of the report.
+    ///
+    /// This method used to be called `as_ref`. It was renamed so that it doesn't shadow
+    /// `AsRef::as_ref`, which now borrows the wrapped error itself rather than creating a report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = Report::new(SuperError { side: SuperErrorSidekick }).indent_width(2);
+    /// let pretty = report.by_ref().pretty();
+    ///
+    /// assert_eq!(report.to_string(), "SuperError is here!: SuperErrorSidekick is here!");
+    /// assert_eq!(
+    ///     pretty.to_string(),
+    ///     "SuperError is here!\n\nCaused by:\n  SuperErrorSidekick is here!",
+    /// );
+    /// ```
+    pub fn by_ref(&self) -> Report<&E> {
+        self.with_source(&self.source)
+    }
+
+    /// Create a report for `source` that has the same formatting options as this one.
+    fn with_source<F>(&self, source: F) -> Report<F>
+    where
+        F: Error,
+    {
+        Report {
+            source,
+            show_backtrace: self.show_backtrace,
+            format: self.format,
+            env_context: self.env_context,
+            env_context_version: self.env_context_version,
+            alternate_inner: self.alternate_inner,
+            thread: self.thread.clone(),
+            redactions: self.redactions.clone(),
+            decorator: self.decorator.clone(),
+            format_each: self.format_each.clone(),
+            static_source: None,
+            prose: self.prose,
+            limits: self.limits,
+            indent_width: self.indent_width,
+            backtrace: self.backtrace.clone(),
+            supplied_backtrace: self.supplied_backtrace.clone(),
+            color: self.color,
+            cause_header: self.cause_header,
+            dedup: self.dedup,
+            separator: self.separator,
+            all_backtraces: self.all_backtraces,
+            compact_numbering: self.compact_numbering,
+            debug_chain: self.debug_chain,
+            wrap_at: self.wrap_at,
+            backtrace_filter: self.backtrace_filter.clone(),
+            skip_redundant: self.skip_redundant,
+            line_prefix: self.line_prefix.clone(),
+            footer: self.footer.clone(),
+            compact_spacing: self.compact_spacing,
+            reverse: self.reverse,
+            help: self.help.clone(),
+            indent_all: self.indent_all,
+            base_indent: self.base_indent,
+            backtrace_hint: self.backtrace_hint,
+            catch_panics: self.catch_panics,
+        }
+    }
+
+    /// Create a report for the error returned by `op`, keeping the formatting options of this one.
+    fn map_source<F, O>(self, op: O) -> Report<F>
+    where
+        F: Error,
+        O: FnOnce(E) -> F,
+    {
+        let Report {
+            source,
+            show_backtrace,
+            format,
+            env_context,
+            env_context_version,
+            alternate_inner,
+            thread,
+            redactions,
+            decorator,
+            format_each,
+            static_source: _,
+            prose,
+            limits,
+            indent_width,
+            backtrace,
+            supplied_backtrace,
+            color,
+            cause_header,
+            dedup,
+            separator,
+            all_backtraces,
+            compact_numbering,
+            debug_chain,
+            wrap_at,
+            backtrace_filter,
+            skip_redundant,
+            line_prefix,
+            footer,
+            compact_spacing,
+            reverse,
+            help,
+            indent_all,
+            base_indent,
+            backtrace_hint,
+            catch_panics,
+        } = self;
+
+        Report {
+            source: op(source),
+            show_backtrace,
+            format,
+            env_context,
+            env_context_version,
+            alternate_inner,
+            thread,
+            redactions,
+            decorator,
+            format_each,
+            static_source: None,
+            prose,
+            limits,
+            indent_width,
+            backtrace,
+            supplied_backtrace,
+            color,
+            cause_header,
+            dedup,
+            separator,
+            all_backtraces,
+            compact_numbering,
+            debug_chain,
+            wrap_at,
+            backtrace_filter,
+            skip_redundant,
+            line_prefix,
+            footer,
+            compact_spacing,
+            reverse,
+            help,
+            indent_all,
+            base_indent,
+            backtrace_hint,
+            catch_panics,
+        }
+    }
+
+    /// Compare the error chain of this report against the chain of `other`.
+    ///
+    /// Returns `None` when every error in both chains renders identically, otherwise returns a
+    /// description of each layer that differs, treating `self` as the expected chain and `other`
+    /// as the actual one. Layers are numbered the same way the pretty format numbers causes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// #
+    /// # fn chain(msgs: &[&'static str]) -> Layer {
+    /// #     let mut msgs = msgs.iter().rev();
+    /// #     let mut error = Layer(msgs.next().unwrap(), None);
+    /// #     for msg in msgs {
+    /// #         error = Layer(msg, Some(Box::new(error)));
+    /// #     }
+    /// #     error
+    /// # }
+    /// let expected = Report::new(chain(&["top", "middle", "root"]));
+    /// let actual = Report::new(chain(&["top", "changed", "root"]));
+    ///
+    /// assert_eq!(expected.diff(&expected), None);
+    /// assert_eq!(
+    ///     expected.diff(&actual).unwrap(),
+    ///     "cause 0 differs: expected `middle`, got `changed`",
+    /// );
+    /// ```
+    pub fn diff<F>(&self, other: &Report<F>) -> Option<String>
+    where
+        F: Error,
+    {
+        let expected = self.messages();
+        let actual = other.messages();
+        let mut diff = String::new();
+
+        for ind in 0..expected.len().max(actual.len()) {
+            let layer = match ind {
+                0 => String::from("error"),
+                ind => format!("cause {}", ind - 1),
+            };
+
+            let line = match (expected.get(ind), actual.get(ind)) {
+                (Some(expected), Some(actual)) if expected == actual => continue,
+                (Some(expected), Some(actual)) => {
+                    format!(
+                        "{} differs: expected `{}`, got `{}`",
+                        layer, expected, actual
+                    )
+                }
+                (Some(expected), None) => format!("{} missing: expected `{}`", layer, expected),
+                (None, Some(actual)) => format!("{} unexpected: got `{}`", layer, actual),
+                (None, None) => unreachable!(),
+            };
+
+            if !diff.is_empty() {
+                diff.push('\n');
+            }
+            diff.push_str(&line);
+        }
+
+        if diff.is_empty() {
+            None
+        } else {
+            Some(diff)
+        }
+    }
+
+    /// Render the error chain as a minimal YAML document.
+    ///
+    /// The wrapped error is emitted under the `error` key and its sources, if any, as a list under
+    /// `caused_by`. Messages that YAML would otherwise misinterpret are emitted as double quoted
+    /// strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let root = Layer("  padded root", None);
+    /// let middle = Layer("key: value", Some(Box::new(root)));
+    /// let report = Report::new(Layer("failed to load config", Some(Box::new(middle))));
+    ///
+    /// assert_eq!(
+    ///     report.to_yaml(),
+    ///     "error: failed to load config\n\
+    ///      caused_by:\n  \
+    ///        - \"key: value\"\n  \
+    ///        - \"  padded root\"\n",
+    /// );
+    ///
+    /// // Messages that YAML would read as a null, bool or number are quoted as well
+    /// for message in ["True", "NULL", "Yes", "on", "n", ".NaN", "0x1F", "0o17", "1_000", "1:30"] {
+    ///     let report = Report::new(Layer(message, None));
+    ///     assert_eq!(report.to_yaml(), format!("error: \"{}\"\n", message));
+    /// }
+    /// ```
+    pub fn to_yaml(&self) -> String {
+        let mut messages = self.messages().into_iter();
+        let mut yaml = String::from("error: ");
+
+        // The chain always contains at least the wrapped error itself
+        write_yaml_scalar(&mut yaml, &messages.next().unwrap_or_default());
+        yaml.push('\n');
+
+        let mut causes = messages.peekable();
+        if causes.peek().is_some() {
+            yaml.push_str("caused_by:\n");
+
+            for cause in causes {
+                yaml.push_str("  - ");
+                write_yaml_scalar(&mut yaml, &cause);
+                yaml.push('\n');
+            }
+        }
+
+        yaml
+    }
+
+    /// Render the error chain as a JSON object.
+    ///
+    /// The object has a `message` field holding the wrapped error's message and a `causes` array
+    /// holding the messages of its sources. When `show_backtrace` is enabled and a backtrace is
+    /// available, it is included as a `backtrace` string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let root = Layer("line one\nline \"two\"", None);
+    /// let report = Report::new(Layer("top", Some(Box::new(root))));
+    ///
+    /// assert_eq!(
+    ///     report.to_json(),
+    ///     r#"{"message":"top","causes":["line one\nline \"two\""]}"#,
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut messages = self.messages().into_iter();
+        let mut json = String::from("{\"message\":");
+
+        // The chain always contains at least the wrapped error itself
+        write_json_string(&mut json, &messages.next().unwrap_or_default());
+
+        json.push_str(",\"causes\":[");
+        for (ind, cause) in messages.enumerate() {
+            if ind > 0 {
+                json.push(',');
+            }
+            write_json_string(&mut json, &cause);
+        }
+        json.push(']');
+
+        if self.show_backtrace {
+            if let Some(backtrace) = self.find_backtrace(&self.source) {
+                let backtrace = self.apply_redactions(self.render_backtrace(backtrace));
+                json.push_str(",\"backtrace\":");
+                write_json_string(&mut json, &backtrace);
+            }
+        }
+
+        json.push('}');
+        json
+    }
+
+    /// Extract a structured entry for every error in the chain, starting with the wrapped error.
+    ///
+    /// The wrapped error is at depth 0 and each source is one deeper than the error it is the
+    /// source of. Only the type of the wrapped error is known to the report, so the `type_name` of
+    /// every other entry is `None`. A wrapped `DynError` is named after the error it was created
+    /// from, as returned by `DynError::type_name`, rather than after `DynError` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let entries = Report::new(SuperError { side: SuperErrorSidekick }).entries();
+    ///
+    /// assert_eq!(entries.len(), 2);
+    /// assert_eq!(entries[0].depth, 0);
+    /// assert_eq!(entries[0].message, "SuperError is here!");
+    /// assert!(entries[0].type_name.as_deref().unwrap().ends_with("SuperError"));
+    /// assert_eq!(entries[1].depth, 1);
+    /// assert_eq!(entries[1].message, "SuperErrorSidekick is here!");
+    /// assert_eq!(entries[1].type_name, None);
+    ///
+    /// # #[cfg(feature = "dyn_error")]
+    /// # {
+    /// use trial_and_error::DynError;
+    ///
+    /// let error = DynError::new("x".parse::<u32>().unwrap_err());
+    /// let entries = Report::new(error).entries();
+    /// assert_eq!(
+    ///     entries[0].type_name.as_deref(),
+    ///     Some("core::num::error::ParseIntError"),
+    /// );
+    /// # }
+    /// ```
+    pub fn entries(&self) -> Vec<ReportEntry> {
+        self.messages()
+            .into_iter()
+            .enumerate()
+            .map(|(depth, message)| ReportEntry {
+                depth,
+                message,
+                type_name: match depth {
+                    0 => Some(String::from(self.source.type_name())),
+                    _ => None,
+                },
+            })
+            .collect()
+    }
+
+    /// Render the parts of the report separately, so they can be laid out some other way, such as
+    /// in a table or as HTML.
+    ///
+    /// Each message is rendered the same way it would be in the report, including any redaction,
+    /// but the parts are left out of the report's layout. The backtrace is the one returned by
+    /// `backtrace`, whether or not `show_backtrace` is enabled, with the backtrace filter applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let rendered = Report::new(Layer("top", Some(Box::new(middle)))).render();
+    ///
+    /// assert_eq!(rendered.message, "top");
+    /// assert_eq!(rendered.causes, ["middle", "root"]);
+    /// assert_eq!(rendered.backtrace, None);
+    /// ```
+    pub fn render(&self) -> RenderedReport {
+        let mut messages = self.messages().into_iter();
+        let message = messages.next().unwrap_or_default();
+        let backtrace = self
+            .find_backtrace(&self.source)
+            .map(|backtrace| self.apply_redactions(self.render_backtrace(backtrace)));
+
+        RenderedReport {
+            message,
+            causes: messages.collect(),
+            backtrace,
+        }
+    }
+
+    /// Render a one line summary of the report, suitable for collapsible log entries.
+    ///
+    /// The summary contains only the wrapped error's message, followed by the number of causes
+    /// hidden behind it when there are any. The full report is still available through `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let report = Report::new(Layer("root", None));
+    /// assert_eq!(report.summary_line(), "root");
+    ///
+    /// let middle = Layer("middle", Some(Box::new(Layer("root", None))));
+    /// let report = Report::new(Layer("top", Some(Box::new(middle))));
+    /// assert_eq!(report.summary_line(), "top (+2 causes)");
+    /// ```
+    pub fn summary_line(&self) -> String {
+        let mut summary = String::new();
+        // Writing into a `String` can't fail
+        let _ = self.fmt_error(self.top_link(), &mut summary);
+
+        match self.errors().count() - 1 {
+            0 => {}
+            1 => summary.push_str(" (+1 cause)"),
+            causes => {
+                let _ = write!(summary, " (+{} causes)", causes);
+            }
+        }
+
+        self.apply_redactions(summary)
+    }
+
+    /// Render every error in the chain, starting with the wrapped error.
+    fn messages(&self) -> Vec<String> {
+        self.errors()
+            .map(|error| self.apply_redactions(self.render_error(error)))
+            .collect()
+    }
+
+    /// Replace every configured secret in `rendered` with `***`.
+    fn apply_redactions(&self, mut rendered: String) -> String {
+        for secret in &self.redactions {
+            rendered = rendered.replace(secret.as_str(), "***");
+        }
+        rendered
+    }
+
+    /// Iterate over every error in the chain, starting with the wrapped error.
+    fn errors(&self) -> impl Iterator<Item = Link<'_>> {
+        let sources = Sources::new(&self.source).map(Link::from);
+
+        std::iter::once(self.top_link()).chain(sources)
+    }
+
+    /// The wrapped error, as the first link of the chain.
+    fn top_link(&self) -> Link<'_> {
+        Link {
+            error: &self.source,
+            as_static: self.static_source.map(|as_static| as_static(&self.source)),
+        }
+    }
+
+    /// Find the backtrace to show for a report starting from `error`.
+    fn find_backtrace<'a>(&'a self, error: &'a dyn Error) -> Option<&'a Backtrace> {
+        let is_captured = |backtrace: &&Backtrace| backtrace.status() == BacktraceStatus::Captured;
+        let supplied = self.supplied_backtrace.as_deref().filter(is_captured);
+        let captured = self.backtrace.as_deref().filter(is_captured);
+
+        supplied.or_else(|| error.backtrace()).or(captured)
+    }
+
+    /// Write the output of `content` to `f`, styled with `style` if color is enabled.
+    fn paint(
+        &self,
+        style: Style,
+        f: &mut dyn Write,
+        content: impl FnOnce(&mut dyn Write) -> fmt::Result,
+    ) -> fmt::Result {
+        if !self.color {
+            return content(f);
+        }
+
+        f.write_str(style.escape_code())?;
+        content(f)?;
+        f.write_str(Style::RESET)
+    }
+
+    /// Format a single error from the chain.
+    fn fmt_error(&self, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        if !self.catch_panics && self.limits.max_message_chars.is_none() {
+            return self.fmt_message(error, f);
+        }
+
+        let mut message = String::new();
+        if self.catch_panics {
+            let formatted = AssertUnwindSafe(|| self.fmt_message(error, &mut message));
+            if let Ok(result) = panic::catch_unwind(formatted) {
+                result?;
+            } else {
+                message = String::from(PANIC_MARKER);
+            }
+        } else {
+            self.fmt_message(error, &mut message)?;
+        }
+
+        if let Some(max_chars) = self.limits.max_message_chars {
+            truncate_chars_with_marker(&mut message, max_chars);
+        }
+        f.write_str(&message)
+    }
+
+    /// Format the message of a single error from the chain, without any length limit.
+    fn fmt_message(&self, link: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        if self.debug_chain {
+            return if self.alternate_inner {
+                write!(f, "{:#?}", link.error)
+            } else {
+                write!(f, "{:?}", link.error)
+            };
+        }
+
+        // The hooks skip a wrapped error that can't be given to them as a `'static` trait object
+        if let (Some(format_each), Some(error)) = (&self.format_each, link.as_static) {
+            let format = &**format_each;
+            return self.fmt_display(&FormatWith { error, format }, f);
+        }
+
+        match (&self.decorator, link.as_static) {
+            (Some(decorate), Some(error)) => self.fmt_display(&*decorate(error), f),
+            _ => self.fmt_display(&link.error, f),
+        }
+    }
+
+    /// Render `backtrace` into a `String`, keeping only the lines accepted by the backtrace filter
+    /// and dropping any trailing whitespace.
+    fn render_backtrace(&self, backtrace: &Backtrace) -> String {
+        let mut rendered = backtrace.to_string();
+
+        if let Some(filter) = &self.backtrace_filter {
+            let mut filtered = String::with_capacity(rendered.len());
+            for line in rendered.lines().filter(|line| filter(line)) {
+                filtered.push_str(line);
+                filtered.push('\n');
+            }
+            rendered = filtered;
+        }
+
+        rendered.truncate(rendered.trim_end().len());
+        rendered
+    }
+
+    /// Whether the multi-line format numbers the causes when there are `total` of them.
+    fn numbers_causes(&self, total: usize) -> bool {
+        if self.compact_numbering {
+            total > 2
+        } else {
+            total > 1
+        }
+    }
+
+    /// Render the report into a `String` in `format`.
+    fn render_to_string(&self, format: ReportFormat) -> String {
+        let mut report = String::new();
+        // Writing into a `String` can't fail
+        let _ = self.fmt_report(format, self.top_link(), &mut report);
+        report
+    }
+
+    /// Whether the message of a single error from the chain is empty.
+    fn renders_empty(&self, error: Link<'_>) -> bool {
+        let mut written = Written(false);
+        // Recording whether anything was written can't fail
+        let _ = self.fmt_error(error, &mut written);
+        !written.0
+    }
+
+    /// Render a single error from the chain into a `String`.
+    fn render_error(&self, error: Link<'_>) -> String {
+        let mut message = String::new();
+        // Writing into a `String` can't fail
+        let _ = self.fmt_error(error, &mut message);
+        message
+    }
+
+    /// Format the displayed form of a single error from the chain.
+    fn fmt_display(&self, display: &dyn fmt::Display, f: &mut dyn Write) -> fmt::Result {
+        if self.alternate_inner {
+            write!(f, "{:#}", display)
+        } else {
+            write!(f, "{}", display)
+        }
+    }
+
+    /// Format the report in `format`.
+    ///
+    /// The report is rendered starting from `error`, which is either the wrapped error or one of
+    /// its sources, and any configured redactions and byte limits are applied to the output.
+    fn fmt_report(&self, format: ReportFormat, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        let prefixes = self.line_prefix.is_some() || self.base_indent > 0;
+
+        if self.redactions.is_empty() && self.limits.max_bytes.is_none() && !self.catch_panics {
+            return if prefixes {
+                self.fmt_raw(format, error, &mut self.prefixed(f))
+            } else {
+                self.fmt_raw(format, error, f)
+            };
+        }
+
+        let mut report = String::new();
+        self.fmt_caught(format, error, &mut report)?;
+        let mut report = self.apply_redactions(report);
+
+        // Prefix the lines before truncating, so that the prefixes count towards `max_bytes`
+        if prefixes {
+            let mut prefixed = String::new();
+            self.prefixed(&mut prefixed).write_str(&report)?;
+            report = prefixed;
+        }
+
+        if let Some(max_bytes) = self.limits.max_bytes {
+            truncate_with_marker(&mut report, max_bytes);
+        }
+
+        f.write_str(&report)
+    }
+
+    /// Wrap `f` in a writer that adds the `line_prefix` and `base_indent` to every line.
+    fn prefixed<'a>(&'a self, f: &'a mut dyn Write) -> Prefixed<'a> {
+        Prefixed {
+            inner: f,
+            prefix: self.line_prefix.as_deref().unwrap_or_default(),
+            indent: self.base_indent,
+            line_start: true,
+        }
+    }
+
+    /// Format the report in `format` into `report`, ending it with a marker instead of unwinding
+    /// if `catch_panics` is enabled and one of the errors panics.
+    fn fmt_caught(
+        &self,
+        format: ReportFormat,
+        error: Link<'_>,
+        report: &mut String,
+    ) -> fmt::Result {
+        if !self.catch_panics {
+            return self.fmt_raw(format, error, report);
+        }
+
+        match panic::catch_unwind(AssertUnwindSafe(|| self.fmt_raw(format, error, report))) {
+            Ok(result) => result,
+            Err(_) => {
+                let separator = match format {
+                    ReportFormat::SingleLine => self.separator,
+                    ReportFormat::MultiLine => "\n",
+                };
+                write!(report, "{}{}", separator, PANIC_MARKER)
+            }
+        }
+    }
+
+    /// Format the report in `format`, without any post-processing.
+    fn fmt_raw(&self, format: ReportFormat, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        match format {
+            ReportFormat::SingleLine => self.fmt_singleline(error, f),
+            ReportFormat::MultiLine => self.fmt_multiline(error, f),
+        }
+    }
+
+    /// Format the report as a single line.
+    fn fmt_singleline(&self, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        let f = &mut Columns {
+            inner: f,
+            column: 0,
+            written: self.skip_redundant.then(String::new),
+        };
+        self.fmt_error(error, f)?;
+
+        let separator = if self.prose {
+            " because "
+        } else {
+            self.separator
+        };
+        let render = |error: &(dyn Error + 'static)| self.render_error(error.into());
+        let mut runs = Runs::new(error.error, self.dedup.then(|| &render as _));
+
+        let causes = runs
+            .by_ref()
+            .filter(|&(cause, _)| !self.renders_empty(cause.into()));
+        for (shown, (cause, count)) in causes.enumerate() {
+            if Some(shown) == self.limits.max_depth {
+                write!(f, "{}...", separator)?;
+                break;
+            }
+
+            if let Some(written) = &f.written {
+                let mut message = self.render_error(cause.into());
+                if self.prose {
+                    message = uncapitalize(&message).into_owned();
+                }
+                if ends_with_message(written, &message, separator) {
+                    continue;
+                }
+            }
+
+            if let Some(wrap_at) = self.wrap_at {
+                let mut segment = self.render_error(cause.into());
+                if self.prose {
+                    segment = uncapitalize(&segment).into_owned();
+                }
+                if count > 1 {
+                    write!(segment, " (x{})", count)?;
+                }
+
+                let first_line = segment.split('\n').next().unwrap_or_default();
+                let width = separator.chars().count() + first_line.chars().count();
+                if f.column + width > wrap_at {
+                    // Break after the separator and continue with a hanging indent
+                    write!(f, "{}\n  ", separator.trim_end())?;
+                } else {
+                    f.write_str(separator)?;
+                }
+                f.write_str(&segment)?;
+                continue;
+            }
+
+            if self.prose {
+                let message = self.render_error(cause.into());
+                write!(f, "{}{}", separator, uncapitalize(&message))?;
+            } else {
+                f.write_str(separator)?;
+                self.fmt_error(cause.into(), f)?;
+            }
+
+            if count > 1 {
+                write!(f, " (x{})", count)?;
+            }
+        }
+
+        if runs.sources.cycle_detected {
+            write!(f, "{}... (cycle detected)", separator)?;
+        }
+
+        if let Some(thread) = &self.thread {
+            write!(f, " (thread: {})", thread)?;
+        }
+
+        if self.env_context {
+            write!(
+                f,
+                " (os: {}, arch: {}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            )?;
+            if let Some(version) = self.env_context_version {
+                write!(f, ", version: {}", version)?;
+            }
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+
+    /// Format the report as multiple lines, with each error cause on its own line.
+    fn fmt_multiline(&self, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        // The reversed format starts with the root cause instead
+        let top = if self.reverse {
+            Sources::new(error.error).last().map_or(error, Link::from)
+        } else {
+            error
+        };
+
+        let top_format = Format::Uniform {
+            width: if self.indent_all {
+                self.indent_width
+            } else {
+                0
+            },
+        };
+        self.paint(Style::Bold, f, |f| {
+            // Without `indent_all`, passed through `Indented` only to drop trailing newlines
+            self.fmt_error(top, &mut Indented::new(f, top_format))
+        })?;
+        self.fmt_help(top, &mut Indented::new(f, top_format))?;
+
+        // The line break that separates the blocks of the report
+        let block_break = if self.compact_spacing { "\n" } else { "\n\n" };
+
+        if let Some(thread) = &self.thread {
+            write!(f, "{}thread: {}", block_break, thread)?;
+        }
+
+        if self.reverse {
+            self.fmt_handled(error, block_break, f)?;
+        } else if error.error.source().is_some() {
+            f.write_str(block_break)?;
+            self.paint(Style::Dimmed, f, |f| f.write_str(self.cause_header))?;
+
+            let render = |error: &(dyn Error + 'static)| self.render_error(error.into());
+            let render = self.dedup.then(|| &render as _);
+            let total = Runs::new(error.error, render)
+                .filter(|&(error, _)| !self.renders_empty(error.into()))
+                .count();
+            let multiple = self.numbers_causes(total);
+            let width = self.indent_width;
+            // Widen the numbers to fit the largest index shown, so that every numbered line and
+            // its continuation lines stay aligned no matter how long the chain is
+            let shown = self
+                .limits
+                .max_depth
+                .map_or(total, |max_depth| total.min(max_depth));
+            let number_width = width.max(decimal_digits(shown.saturating_sub(1)));
+            let mut runs = Runs::new(error.error, render);
+            let mut truncated = None;
+
+            let causes = runs
+                .by_ref()
+                .filter(|&(error, _)| !self.renders_empty(error.into()));
+            for (ind, (error, count)) in causes.enumerate() {
+                if Some(ind) == self.limits.max_depth {
+                    truncated = Some(count);
+                    break;
+                }
+
+                let format = self.cause_format(multiple.then(|| ind), number_width);
+                self.fmt_cause(error.into(), count, format, f)?;
+            }
+
+            if let Some(count) = truncated {
+                // The run of causes that hit the limit was already taken from `runs`
+                let remaining = count + runs.remaining();
+                write!(
+                    f,
+                    "\n{:width$}... and {} more",
+                    "",
+                    remaining,
+                    width = width
+                )?;
+            } else if runs.sources.cycle_detected {
+                write!(f, "\n{:width$}... (cycle detected)", "", width = width)?;
+            }
+        }
+
+        if self.show_backtrace {
+            let backtrace = self.find_backtrace(error.error).filter(|backtrace| {
+                !self.backtrace_hint || backtrace.status() == BacktraceStatus::Captured
+            });
+
+            if let Some(backtrace) = backtrace {
+                let backtrace = self.render_backtrace(backtrace);
+                f.write_str(block_break)?;
+                writeln!(f, "Stack backtrace:")?;
+                write!(f, "{}", backtrace)?;
+            } else if self.backtrace_hint {
+                write!(
+                    f,
+                    "{}Backtrace: unavailable (set RUST_BACKTRACE=1)",
+                    block_break
+                )?;
+            }
+        }
+
+        if self.env_context {
+            write!(f, "{}Environment:", block_break)?;
+            write!(f, "\n    os: {}", std::env::consts::OS)?;
+            write!(f, "\n    arch: {}", std::env::consts::ARCH)?;
+            if let Some(version) = self.env_context_version {
+                write!(f, "\n    version: {}", version)?;
+            }
+        }
+
+        if let Some(footer) = &self.footer {
+            write!(f, "{}{}", block_break, footer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Format the errors the root cause of `error` was handled by, from the innermost outward, as
+    /// shown by the reversed multi-line format.
+    fn fmt_handled(&self, error: Link<'_>, block_break: &str, f: &mut dyn Write) -> fmt::Result {
+        let render = |error: &(dyn Error + 'static)| self.render_error(error.into());
+        let mut runs = Runs::new(error.error, self.dedup.then(|| &render as _));
+        let mut links = std::iter::once((error, 1)).chain(
+            runs.by_ref()
+                .map(|(source, count)| (Link::from(source), count)),
+        );
+
+        // Only the innermost `max_depth` entries are shown, so the ones outside of them are
+        // counted instead of kept, along with one more for the root cause
+        let capacity = self.limits.max_depth.map(|max_depth| max_depth + 1);
+        let mut handled: VecDeque<(Link<'_>, usize)> = VecDeque::new();
+        let mut omitted = (0, 0);
+        let mut root_shown = false;
+        for (ind, (error, count)) in links.by_ref().enumerate() {
+            root_shown = !self.renders_empty(error);
+            if !root_shown {
+                continue;
+            }
+
+            // `Runs` only collapses the sources, so the wrapped error may still repeat the first
+            let repeats_top = self.dedup
+                && ind == 1
+                && handled.len() == 1
+                && self.render_error(handled[0].0) == self.render_error(error);
+            if repeats_top {
+                handled[0].1 += count;
+            } else {
+                handled.push_back((error, count));
+            }
+
+            if capacity.map_or(false, |capacity| handled.len() > capacity) {
+                let (_, count) = handled.pop_front().unwrap();
+                omitted = (omitted.0 + 1, omitted.1 + count);
+            }
+        }
+
+        // The root cause is already shown as the top line
+        if root_shown {
+            match handled.back_mut() {
+                Some((_, count)) if *count > 1 => *count -= 1,
+                _ => {
+                    handled.pop_back();
+                }
+            }
+        }
+        if let Some(max_depth) = self.limits.max_depth {
+            if handled.len() > max_depth {
+                let (_, count) = handled.pop_front().unwrap();
+                omitted = (omitted.0 + 1, omitted.1 + count);
+            }
+        }
+
+        let cycle_detected = runs.sources.cycle_detected;
+        if handled.is_empty() && omitted.0 == 0 && !cycle_detected {
+            return Ok(());
+        }
+
+        f.write_str(block_break)?;
+        self.paint(Style::Dimmed, f, |f| f.write_str("While handling:"))?;
+
+        let multiple = self.numbers_causes(handled.len() + omitted.0);
+        let number_width = self
+            .indent_width
+            .max(decimal_digits(handled.len().saturating_sub(1)));
+        for (ind, (error, count)) in handled.into_iter().rev().enumerate() {
+            let format = self.cause_format(multiple.then(|| ind), number_width);
+            self.fmt_cause(error, count, format, f)?;
+        }
+
+        let width = self.indent_width;
+        if omitted.0 > 0 {
+            write!(
+                f,
+                "\n{:width$}... and {} more",
+                "",
+                omitted.1,
+                width = width
+            )?;
+        } else if cycle_detected {
+            write!(f, "\n{:width$}... (cycle detected)", "", width = width)?;
+        }
+
+        Ok(())
+    }
+
+    /// Format the advice offered for `error`, if any, on the lines following its message.
+    fn fmt_help(&self, error: Link<'_>, f: &mut dyn Write) -> fmt::Result {
+        match self.help.as_ref().and_then(|help| help(error.error)) {
+            Some(help) => write!(f, "\nhelp: {}", help),
+            None => Ok(()),
+        }
+    }
+
+    /// The indentation of a cause in the multi-line format, numbered with `ind` if given.
+    fn cause_format(&self, ind: Option<usize>, number_width: usize) -> Format {
+        match ind {
+            Some(ind) => Format::Numbered {
+                ind,
+                width: number_width,
+                color: self.color,
+            },
+            None => Format::Uniform {
+                width: self.indent_width,
+            },
+        }
+    }
+
+    /// Format one entry of the causes in the multi-line format, standing for `count` causes that
+    /// rendered identically.
+    fn fmt_cause(
+        &self,
+        error: Link<'_>,
+        count: usize,
+        format: Format,
+        f: &mut dyn Write,
+    ) -> fmt::Result {
+        writeln!(f)?;
+        let mut indented = Indented::new(f, format);
+        self.fmt_error(error, &mut indented)?;
+        if count > 1 {
+            write!(indented, " (x{})", count)?;
+        }
+        self.fmt_help(error, &mut indented)?;
+
+        if self.show_backtrace && self.all_backtraces {
+            if let Some(backtrace) = error.error.backtrace() {
+                let backtrace = self.render_backtrace(backtrace);
+                write!(indented, "\n\nStack backtrace:\n{}", backtrace)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<E> Report<E>
+where
+    E: Error + 'static,
+{
+    /// Iterate over the error chain, starting with the wrapped error followed by each source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = Report::new(SuperError { side: SuperErrorSidekick });
+    /// let messages: Vec<String> = report.chain().map(ToString::to_string).collect();
+    ///
+    /// assert_eq!(messages, ["SuperError is here!", "SuperErrorSidekick is here!"]);
+    /// assert!(report.chain().last().unwrap().is::<SuperErrorSidekick>());
+    ///
+    /// let report = Report::new(SuperErrorSidekick);
+    /// assert_eq!(report.chain().count(), 1);
+    /// ```
+    ///
+    /// The chain stops before any source that repeats an earlier error, and the formatted report
+    /// marks where the cycle was cut off.
+    ///
+    /// ```rust
+    /// # use std::{error::Error, fmt};
+    /// # use trial_and_error::Report;
+    /// #[derive(Debug)]
+    /// struct Ouroboros;
+    ///
+    /// # impl fmt::Display for Ouroboros {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str("ouroboros")
+    /// #     }
+    /// # }
+    /// impl Error for Ouroboros {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(self)
+    ///     }
+    /// }
+    ///
+    /// let report = Report::new(Ouroboros);
+    /// assert_eq!(report.chain().count(), 1);
+    /// assert_eq!(report.to_string(), "ouroboros: ... (cycle detected)");
+    ///
+    /// let report = report.pretty();
+    /// assert_eq!(report.to_string(), "ouroboros\n\nCaused by:\n    ... (cycle detected)");
+    /// ```
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        let error: &(dyn Error + 'static) = &self.source;
+        std::iter::once(error).chain(Sources::new(error))
+    }
+
+    /// Iterate over the error chain along with the index the multi-line format numbers each
+    /// error with.
+    ///
+    /// The wrapped error never has an index, and neither do the causes when the report wouldn't
+    /// number them. Collapsing with `dedup` and the `max_depth` limit aren't taken into account,
+    /// so every error in the chain is yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// let lower = Layer("lower", Some(Box::new(Layer("root", None))));
+    /// let error = Layer("top", Some(Box::new(Layer("upper", Some(Box::new(lower))))));
+    /// let report = Report::new(error).pretty();
+    ///
+    /// let mut rendered = String::new();
+    /// for (ind, error) in report.iter_with_indices() {
+    ///     match ind {
+    ///         None => rendered.push_str(&format!("{}\n\nCaused by:", error)),
+    ///         Some(ind) => rendered.push_str(&format!("\n{: >4}: {}", ind, error)),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(rendered, report.to_string());
+    /// ```
+    pub fn iter_with_indices(
+        &self,
+    ) -> impl Iterator<Item = (Option<usize>, &(dyn Error + 'static))> {
+        let numbered = self.numbers_causes(self.chain().count() - 1);
+        self.chain().enumerate().map(move |(ind, error)| {
+            let ind = ind.checked_sub(1).filter(|_| numbered);
+            (ind, error)
+        })
+    }
+
+    /// Returns the last error in the chain, or the wrapped error itself if it has no source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Outer(SuperError);
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for Outer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "Outer is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Outer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let report = Report::new(Outer(SuperError { side: SuperErrorSidekick }));
+    ///
+    /// assert!(report.root_cause().is::<SuperErrorSidekick>());
+    /// assert_eq!(report.top().to_string(), "Outer is here!");
+    ///
+    /// let report = Report::new(SuperErrorSidekick);
+    /// assert!(report.root_cause().is::<SuperErrorSidekick>());
+    /// ```
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        let error: &(dyn Error + 'static) = &self.source;
+        Sources::new(error).last().unwrap_or(error)
+    }
+
+    /// Add a message of context on top of the wrapped error.
+    ///
+    /// The context becomes the new top error of the report, with the original error as its source.
+    /// This is also the way to replace a technical top-level message with a friendlier one, since
+    /// the original error is still shown as the first cause.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperError {
+    /// #     side: SuperErrorSidekick,
+    /// # }
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct SuperErrorSidekick;
+    /// #
+    /// # impl fmt::Display for SuperError {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperError is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl fmt::Display for SuperErrorSidekick {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         write!(f, "SuperErrorSidekick is here!")
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperError {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         Some(&self.side)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for SuperErrorSidekick {}
+    /// let error = SuperError { side: SuperErrorSidekick };
+    /// let report = Report::new(error).pretty().context("while doing X");
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "while doing X\n\n\
+    ///      Caused by:\n   \
+    ///         0: SuperError is here!\n   \
+    ///         1: SuperErrorSidekick is here!",
+    /// );
+    /// assert_eq!(*report.inner().context(), "while doing X");
+    ///
+    /// let error = SuperError { side: SuperErrorSidekick };
+    /// let report = Report::new(error).context("Something went wrong");
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "Something went wrong: SuperError is here!: SuperErrorSidekick is here!",
+    /// );
+    /// ```
+    pub fn context<C>(self, context: C) -> Report<Context<C, E>>
+    where
+        C: fmt::Display,
+    {
+        self.map_source(|error| Context { context, error })
+    }
+}
+
+#[cfg(feature = "dyn_error")]
+impl Report<DynError> {
+    /// Enable showing the thread the wrapped `DynError` was created on.
+    ///
+    /// The thread is identified by its name, or by its id for unnamed threads.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::thread;
+    /// use trial_and_error::{DynResult, Report};
+    ///
+    /// fn parse() -> DynResult<u32> {
+    ///     let parsed = "four".parse::<u32>()?;
+    ///
+    ///     DynResult::Ok(parsed)
+    /// }
+    ///
+    /// let error = thread::Builder::new()
+    ///     .name(String::from("worker-3"))
+    ///     .spawn(|| match parse() {
+    ///         DynResult::Ok(_) => unreachable!(),
+    ///         DynResult::Err(error) => error,
+    ///     })
+    ///     .unwrap()
+    ///     .join()
+    ///     .unwrap();
+    ///
+    /// let report = Report::new(error).pretty().show_thread(true);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "invalid digit found in string\n\nthread: worker-3",
+    /// );
+    /// ```
+    pub fn show_thread(mut self, show_thread: bool) -> Self {
+        self.thread = if show_thread {
+            let name = self.source.thread_name().map(String::from);
+            Some(name.unwrap_or_else(|| format!("{:?}", self.source.thread_id())))
+        } else {
+            None
+        };
+        self
+    }
+}
+
+impl Report<BoxedError> {
+    /// Create a new `Report` from a type erased error.
+    ///
+    /// `Box<dyn Error>` doesn't implement `Error` itself, so it can't be passed to `Report::new`.
+    /// The box is wrapped in a `BoxedError` instead, which forwards to the boxed error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::fmt;
+    /// # use std::error::Error;
+    /// # use trial_and_error::Report;
+    /// #
+    /// # #[derive(Debug)]
+    /// # struct Layer(&'static str, Option<Box<Layer>>);
+    /// #
+    /// # impl fmt::Display for Layer {
+    /// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// #         f.write_str(self.0)
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Error for Layer {
+    /// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    /// #         self.1.as_deref().map(|e| e as _)
+    /// #     }
+    /// # }
+    /// fn load() -> Result<(), Box<dyn Error>> {
+    ///     let root = Layer("file not found", None);
+    ///     Err(Box::new(Layer("could not load config", Some(Box::new(root)))))
+    /// }
+    ///
+    /// let report = Report::from_boxed(load().unwrap_err());
+    /// assert_eq!(report.to_string(), "could not load config: file not found");
+    /// ```
+    pub fn from_boxed(error: Box<dyn Error + 'static>) -> Self {
+        Report::new(BoxedError(error))
+    }
+}
+
+/// Converting an error into a `Report` is what lets `Report<E>` be used as the error type of an
+/// ordinary `Result`: the `?` operator applies this conversion to an `E` automatically.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError;
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {}
+/// fn fallible() -> Result<(), SuperError> {
+///     Err(SuperError)
+/// }
+///
+/// fn run() -> Result<(), Report<SuperError>> {
+///     fallible()?;
+///     Ok(())
+/// }
+///
+/// let report = run().unwrap_err();
+/// assert_eq!(report.to_string(), "SuperError is here!");
+/// ```
+impl<E> From<E> for Report<E>
+where
+    E: Error,
+{
+    fn from(source: E) -> Self {
+        Report::new(source)
+    }
+}
+
+/// Renders the report into a `String`, in whichever format it is configured with.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError {
+/// #     side: SuperErrorSidekick,
+/// # }
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         Some(&self.side)
+/// #     }
+/// # }
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperErrorSidekick;
+/// #
+/// # impl fmt::Display for SuperErrorSidekick {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperErrorSidekick is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperErrorSidekick {}
+/// let report = || Report::new(SuperError { side: SuperErrorSidekick });
+///
+/// let rendered: String = report().into();
+/// assert_eq!(rendered, "SuperError is here!: SuperErrorSidekick is here!");
+///
+/// let rendered: String = report().pretty().into();
+/// assert_eq!(rendered, "SuperError is here!\n\nCaused by:\n    SuperErrorSidekick is here!");
+/// ```
+impl<E> From<Report<E>> for String
+where
+    E: Error,
+{
+    fn from(report: Report<E>) -> Self {
+        report.to_string()
+    }
+}
 
-use std::{
-    error::Error,
-    fmt::{self, Write},
-};
+/// Cloning a `Report` clones the wrapped error and keeps every formatting option. A backtrace
+/// captured by the report is shared between the clones rather than captured again.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug, Clone)]
+/// # struct SuperError {
+/// #     side: SuperErrorSidekick,
+/// # }
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         Some(&self.side)
+/// #     }
+/// # }
+/// #
+/// # #[derive(Debug, Clone)]
+/// # struct SuperErrorSidekick;
+/// #
+/// # impl fmt::Display for SuperErrorSidekick {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperErrorSidekick is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperErrorSidekick {}
+/// let report = Report::new(SuperError { side: SuperErrorSidekick })
+///     .pretty()
+///     .indent_width(2)
+///     .cause_header("Because:");
+/// let retry = report.clone();
+///
+/// assert_eq!(retry.to_string(), report.to_string());
+/// assert_eq!(
+///     retry.to_string(),
+///     "SuperError is here!\n\nBecause:\n  SuperErrorSidekick is here!",
+/// );
+/// ```
+impl<E> Clone for Report<E>
+where
+    E: Error + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut clone = self.with_source(self.source.clone());
+        clone.static_source = self.static_source;
+        clone
+    }
+}
 
-/// The main `Report` type.
-pub struct Report<E> {
-    /// The error being reported.
-    source: E,
-    /// Whether the full backtrace should be included as part of the report.
-    show_backtrace: bool,
-    /// Whether the report should be pretty printed.
-    pretty: bool,
+/// Borrows the wrapped error, for passing a `Report` to code generic over `AsRef<E>`.
+///
+/// To borrow the error as a report with the same options instead, use `Report::by_ref`, which
+/// used to be the inherent `Report::as_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io;
+/// use trial_and_error::Report;
+///
+/// fn kind(error: impl AsRef<io::Error>) -> io::ErrorKind {
+///     error.as_ref().kind()
+/// }
+///
+/// let report = Report::new(io::Error::from(io::ErrorKind::NotFound));
+/// assert_eq!(report.as_ref().kind(), io::ErrorKind::NotFound);
+/// assert_eq!(kind(report), io::ErrorKind::NotFound);
+/// ```
+impl<E> AsRef<E> for Report<E>
+where
+    E: Error,
+{
+    fn as_ref(&self) -> &E {
+        &self.source
+    }
 }
 
-impl<E> Report<E>
+/// Dereferences to the wrapped error, so that its methods can be called on the report directly.
+///
+/// Methods that `Report` has itself, including those of the traits it implements like
+/// `to_string` and `source`, are found before those of the wrapped error, so they describe the
+/// whole report rather than the error. Use `Report::inner` when the distinction matters.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #[derive(Debug)]
+/// struct HttpError {
+///     status: u16,
+/// }
+///
+/// impl HttpError {
+///     fn is_retryable(&self) -> bool {
+///         self.status >= 500
+///     }
+/// }
+/// #
+/// # impl fmt::Display for HttpError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "request failed with status {}", self.status)
+/// #     }
+/// # }
+/// #
+/// # impl Error for HttpError {}
+///
+/// fn status_of(error: &HttpError) -> u16 {
+///     error.status
+/// }
+///
+/// let report = Report::new(HttpError { status: 503 });
+///
+/// assert!(report.is_retryable());
+/// assert_eq!(status_of(&report), 503);
+/// assert_eq!(AsRef::<HttpError>::as_ref(&report).status, 503);
+/// ```
+impl<E> Deref for Report<E>
 where
     E: Error,
 {
-    /// Create a new `Report` from an input error.
-    pub fn new(source: E) -> Report<E> {
-        Report {
-            source,
-            show_backtrace: false,
-            pretty: false,
-        }
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.source
     }
-    
-    /// Enable pretty-printing the report.
-    pub fn pretty(mut self) -> Self {
-        self.pretty = true;
-        self
+}
+
+/// The report is rendered in the format configured on the report, unless the alternate flag is
+/// given, as in `{:#}`, which always renders the multi-line format.
+///
+/// Causes whose message is empty are left out of either format, rather than showing up as a
+/// dangling separator or a blank line.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct Layer(&'static str, Option<Box<Layer>>);
+/// #
+/// # impl fmt::Display for Layer {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         f.write_str(self.0)
+/// #     }
+/// # }
+/// #
+/// # impl Error for Layer {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         self.1.as_deref().map(|e| e as _)
+/// #     }
+/// # }
+/// let report = Report::new(Layer("top", Some(Box::new(Layer("root", None)))));
+///
+/// assert_eq!(format!("{}", report), "top: root");
+/// assert_eq!(format!("{:#}", report), "top\n\nCaused by:\n    root");
+///
+/// let silent = Layer("", Some(Box::new(Layer("root", None))));
+/// let report = Report::new(Layer("top", Some(Box::new(silent))));
+///
+/// assert_eq!(format!("{}", report), "top: root");
+/// assert_eq!(format!("{:#}", report), "top\n\nCaused by:\n    root");
+/// ```
+impl<E> fmt::Display for Report<E>
+where
+    E: Error,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = if f.alternate() {
+            ReportFormat::MultiLine
+        } else {
+            self.format
+        };
+
+        self.fmt_report(format, self.top_link(), f)
     }
-    
-    /// Enable showing a backtrace for the report.
-    pub fn show_backtrace(mut self) -> Self {
-        self.show_backtrace = true;
-        self
+}
+
+/// A `Report` is itself an error, which forwards `source` and `backtrace` to the error it wraps.
+///
+/// This lets a `Report` be stored as a `Box<dyn Error>` or used as the source of another error.
+/// Note that since a report's `Display` output already includes the rest of the chain, when a report
+/// is used in the middle of a chain that is rendered by another report, its sources appear twice.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(error_iter)]
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError {
+/// #     side: SuperErrorSidekick,
+/// # }
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         Some(&self.side)
+/// #     }
+/// # }
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperErrorSidekick;
+/// #
+/// # impl fmt::Display for SuperErrorSidekick {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperErrorSidekick is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperErrorSidekick {}
+/// let report = Report::new(SuperError { side: SuperErrorSidekick });
+/// let boxed: Box<dyn Error> = Box::new(report);
+///
+/// let chain: Vec<String> = boxed.chain().map(ToString::to_string).collect();
+/// assert_eq!(
+///     chain,
+///     [
+///         "SuperError is here!: SuperErrorSidekick is here!",
+///         "SuperErrorSidekick is here!",
+///     ],
+/// );
+/// ```
+impl<E> Error for Report<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.source()
     }
-    
-    /// Format the report as a single line.
-    fn fmt_singleline(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.source)?;
 
-        let sources = self
-            .source
-            .source()
-            .into_iter()
-            .flat_map(<dyn Error>::chain);
+    fn backtrace(&self) -> Option<&Backtrace> {
+        self.find_backtrace(&self.source)
+    }
+}
 
-        for cause in sources {
-            write!(f, ": {}", cause)?;
+/// The plain `{:?}` format renders the report exactly like `Display` does, since that is the format
+/// used when a `Report` is `unwrap`ed or returned from `main`. The alternate `{:#?}` format instead
+/// shows the structure of the report itself: the wrapped error, the formatting options, and the
+/// number of errors in the chain. Secrets given to `redact` are hidden from the `Debug` output of
+/// the wrapped error as well.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError;
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {}
+/// let report = Report::new(SuperError).pretty();
+///
+/// assert_eq!(format!("{:?}", report), "SuperError is here!");
+/// let debug = format!("{:#?}", report);
+/// assert!(debug.starts_with("Report {\n    source: SuperError,\n"));
+/// assert!(debug.contains("\n    format: MultiLine,\n"));
+/// assert!(debug.contains("\n    depth: 1,\n"));
+///
+/// #[derive(Debug)]
+/// struct LoginError {
+///     token: &'static str,
+/// }
+/// #
+/// # impl fmt::Display for LoginError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "login failed")
+/// #     }
+/// # }
+/// #
+/// # impl Error for LoginError {}
+///
+/// let report = Report::new(LoginError { token: "hunter2" }).redact(&["hunter2"]);
+/// let debug = format!("{:#?}", report);
+/// assert!(debug.contains("token: \"***\""));
+/// assert!(!debug.contains("hunter2"));
+/// ```
+impl<E> fmt::Debug for Report<E>
+where
+    E: Error,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return fmt::Display::fmt(self, f);
         }
 
-        Ok(())
+        let depth = self.errors().count();
+        // The secrets would otherwise show up in the fields the rendered report redacts
+        let source = self.apply_redactions(format!("{:#?}", self.source));
+        let redact = |text: &Option<String>| text.clone().map(|text| self.apply_redactions(text));
+
+        f.debug_struct("Report")
+            .field("source", &format_args!("{}", source))
+            .field("show_backtrace", &self.show_backtrace)
+            .field("format", &self.format)
+            .field("env_context", &self.env_context)
+            .field("env_context_version", &self.env_context_version)
+            .field("alternate_inner", &self.alternate_inner)
+            .field("thread", &redact(&self.thread))
+            .field("redactions", &self.redactions.len())
+            .field("decorator", &self.decorator.is_some())
+            .field("format_each", &self.format_each.is_some())
+            .field("prose", &self.prose)
+            .field("limits", &self.limits)
+            .field("indent_width", &self.indent_width)
+            .field("backtrace", &self.backtrace.is_some())
+            .field("supplied_backtrace", &self.supplied_backtrace.is_some())
+            .field("color", &self.color)
+            .field("cause_header", &self.cause_header)
+            .field("dedup", &self.dedup)
+            .field("separator", &self.separator)
+            .field("all_backtraces", &self.all_backtraces)
+            .field("compact_numbering", &self.compact_numbering)
+            .field("debug_chain", &self.debug_chain)
+            .field("wrap_at", &self.wrap_at)
+            .field("backtrace_filter", &self.backtrace_filter.is_some())
+            .field("skip_redundant", &self.skip_redundant)
+            .field("line_prefix", &self.line_prefix)
+            .field("footer", &redact(&self.footer))
+            .field("compact_spacing", &self.compact_spacing)
+            .field("reverse", &self.reverse)
+            .field("help", &self.help.is_some())
+            .field("indent_all", &self.indent_all)
+            .field("base_indent", &self.base_indent)
+            .field("backtrace_hint", &self.backtrace_hint)
+            .field("catch_panics", &self.catch_panics)
+            .field("depth", &depth)
+            .finish()
     }
+}
 
-    /// Format the report as multiple lines, with each error cause on its own line.
-    fn fmt_multiline(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let error = &self.source;
+/// Returning a `Report` from `main` prints it to stderr, prefixed with `Error: `, and exits with a
+/// status code of 1.
+///
+/// # Examples
+///
+/// ```rust,should_panic
+/// # use std::fmt;
+/// # use std::error::Error;
+/// use trial_and_error::Report;
+///
+/// #[derive(Debug)]
+/// struct SuperError;
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {}
+///
+/// // Prints `Error: SuperError is here!` and exits with status code 1
+/// fn main() -> Report<SuperError> {
+///     Report::new(SuperError)
+/// }
+/// ```
+///
+/// ```rust
+/// #![feature(termination_trait_lib)]
+/// # use std::fmt;
+/// # use std::error::Error;
+/// use std::process::Termination;
+/// use trial_and_error::Report;
+/// #
+/// # #[derive(Debug)]
+/// # struct SuperError;
+/// #
+/// # impl fmt::Display for SuperError {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         write!(f, "SuperError is here!")
+/// #     }
+/// # }
+/// #
+/// # impl Error for SuperError {}
+///
+/// assert_eq!(Report::new(SuperError).report(), 1);
+/// ```
+impl<E> Termination for Report<E>
+where
+    E: Error,
+{
+    fn report(self) -> i32 {
+        eprintln!("Error: {:?}", self);
+        1
+    }
+}
 
-        write!(f, "{}", error)?;
+/// Write a report of `error` and its sources into `w`, without creating a `Report`.
+///
+/// This renders the same output as a `Report` with the default options, in the multi-line format
+/// if `pretty` is set, so other `Display` impls can embed a report of an error they hold. Options
+/// set with `set_default_options` don't apply.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// use trial_and_error::error_reporter::write_report;
+/// #
+/// # #[derive(Debug)]
+/// # struct Layer(&'static str, Option<Box<Layer>>);
+/// #
+/// # impl fmt::Display for Layer {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         f.write_str(self.0)
+/// #     }
+/// # }
+/// #
+/// # impl Error for Layer {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         self.1.as_deref().map(|e| e as _)
+/// #     }
+/// # }
+///
+/// struct JobFailed {
+///     job: u32,
+///     error: Layer,
+/// }
+///
+/// impl fmt::Display for JobFailed {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "job {} failed: ", self.job)?;
+///         write_report(&self.error, false, f)
+///     }
+/// }
+///
+/// let error = Layer("upload failed", Some(Box::new(Layer("timed out", None))));
+/// let failed = JobFailed { job: 7, error };
+///
+/// assert_eq!(failed.to_string(), "job 7 failed: upload failed: timed out");
+/// ```
+pub fn write_report<W>(error: &dyn Error, pretty: bool, w: &mut W) -> fmt::Result
+where
+    W: Write,
+{
+    let format = if pretty {
+        ReportFormat::MultiLine
+    } else {
+        ReportFormat::SingleLine
+    };
 
-        if let Some(cause) = error.source() {
-            write!(f, "\n\nCaused by:")?;
+    Report::with_options(error, ReportOptions::default())
+        .format(format)
+        .write_to(w)
+}
 
-            let multiple = cause.source().is_some();
-            let format = if multiple {
-                Format::Numbered { ind: 0 }
-            } else {
-                Format::Uniform {
-                    indentation: "    ",
+/// Format `line` the way the multi-line format of a `Report` with the default indent width shows
+/// the cause numbered `index`, when the largest index shown is `max_index`.
+///
+/// The index is right aligned to the width shared by every cause of the report, and any
+/// continuation lines in `line` are indented to line up with the start of the first one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::fmt;
+/// # use std::error::Error;
+/// # use trial_and_error::Report;
+/// use trial_and_error::error_reporter::format_numbered_line;
+/// #
+/// # #[derive(Debug)]
+/// # struct Layer(String, Option<Box<Layer>>);
+/// #
+/// # impl fmt::Display for Layer {
+/// #     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// #         f.write_str(&self.0)
+/// #     }
+/// # }
+/// #
+/// # impl Error for Layer {
+/// #     fn source(&self) -> Option<&(dyn Error + 'static)> {
+/// #         self.1.as_deref().map(|e| e as _)
+/// #     }
+/// # }
+/// assert_eq!(format_numbered_line(0, 1, "middle"), "   0: middle");
+/// assert_eq!(format_numbered_line(1, 1, "root\ncause"), "   1: root\n      cause");
+///
+/// // The numbers are widened to fit the largest index
+/// assert_eq!(format_numbered_line(12344, 12344, "root"), "12344: root");
+/// assert_eq!(format_numbered_line(7, 12344, "middle"), "    7: middle");
+///
+/// // A chain with 11 causes below the wrapped error
+/// let error = (0..=11).rev().fold(None, |source, depth| {
+///     Some(Layer(format!("layer {}", depth), source.map(Box::new)))
+/// });
+/// let error = error.unwrap();
+///
+/// let report = Report::new(&error).pretty().to_string();
+/// let lines: Vec<_> = report.lines().skip(3).collect();
+/// for &index in &[0, 9, 10] {
+///     let line = format!("layer {}", index + 1);
+///     assert_eq!(format_numbered_line(index, 10, &line), lines[index]);
+/// }
+///
+/// // Reports widen their numbers the same way when the indent width is too narrow for them
+/// let report = Report::new(&error).pretty().indent_width(1).to_string();
+/// let lines: Vec<_> = report.lines().skip(3).collect();
+/// assert_eq!(lines[0], " 0: layer 1");
+/// assert_eq!(lines[10], "10: layer 11");
+/// ```
+pub fn format_numbered_line(index: usize, max_index: usize, line: &str) -> String {
+    let format = Format::Numbered {
+        ind: index,
+        width: DEFAULT_INDENT_WIDTH.max(decimal_digits(max_index)),
+        color: false,
+    };
+
+    let mut numbered = String::new();
+    // Writing into a `String` can't fail
+    let _ = Indented::new(&mut numbered, format).write_str(line);
+    numbered
+}
+
+/// A writer that keeps track of the column the next character is written at, and optionally of
+/// everything written so far.
+struct Columns<'a> {
+    inner: &'a mut dyn Write,
+    column: usize,
+    written: Option<String>,
+}
+
+impl Write for Columns<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match s.rfind('\n') {
+            Some(newline) => self.column = s[newline + 1..].chars().count(),
+            None => self.column += s.chars().count(),
+        }
+        if let Some(written) = &mut self.written {
+            written.push_str(s);
+        }
+        self.inner.write_str(s)
+    }
+}
+
+/// A writer that discards its output, only recording whether there was any.
+struct Written(bool);
+
+impl Write for Written {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 |= !s.is_empty();
+        Ok(())
+    }
+}
+
+/// A writer that starts every line with a prefix, followed by a number of spaces.
+struct Prefixed<'a> {
+    inner: &'a mut dyn Write,
+    prefix: &'a str,
+    indent: usize,
+    /// Whether nothing has been written on the current line yet.
+    line_start: bool,
+}
+
+impl Write for Prefixed<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (ind, line) in s.split('\n').enumerate() {
+            if ind > 0 {
+                if self.line_start {
+                    // Keep blank lines free of trailing whitespace
+                    self.inner.write_str(self.prefix.trim_end())?;
                 }
-            };
+                self.inner.write_char('\n')?;
+                self.line_start = true;
+            }
 
-            for error in cause.chain() {
-                writeln!(f)?;
-                let mut indented = Indented {
-                    inner: f,
-                    needs_indent: true,
-                    format,
-                };
-                write!(indented, "{}", error)?;
+            if line.is_empty() {
+                continue;
             }
-        }
 
-        if self.show_backtrace {
-            let backtrace = error.backtrace();
-            if let Some(backtrace) = backtrace {
-                let mut backtrace = backtrace.to_string();
-                write!(f, "\n\n")?;
-                writeln!(f, "Stack backtrace:")?;
-                backtrace.truncate(backtrace.trim_end().len());
-                write!(f, "{}", backtrace)?;
+            if self.line_start {
+                write!(
+                    self.inner,
+                    "{}{:indent$}",
+                    self.prefix,
+                    "",
+                    indent = self.indent
+                )?;
+                self.line_start = false;
             }
+            self.inner.write_str(line)?;
         }
 
         Ok(())
     }
 }
 
-// is it possible to make this work for types that convert into the inner error type?
-impl<E> From<E> for Report<E>
-where
-    E: Error,
-{
-    fn from(source: E) -> Self {
-        Report::new(source)
-    }
+/// Displays a report in a given format, whatever format it was configured with.
+struct InFormat<'a, E> {
+    report: &'a Report<E>,
+    format: ReportFormat,
 }
 
-impl<E> fmt::Display for Report<E>
+impl<E> fmt::Display for InFormat<'_, E>
 where
     E: Error,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.pretty {
-            self.fmt_multiline(f)
-        } else {
-            self.fmt_singleline(f)
-        }
+        self.report
+            .fmt_report(self.format, self.report.top_link(), f)
     }
 }
 
-// This type intentionally outputs the same format for `Display` and `Debug` for
-// situations where you `unwrap` a `Report` or return it from main.
-impl<E> fmt::Debug for Report<E>
-where
-    E: Error,
-{
+/// Displays an error using a user supplied format function.
+struct FormatWith<'a> {
+    error: &'a (dyn Error + 'static),
+    format:
+        &'a (dyn Fn(&(dyn Error + 'static), &mut fmt::Formatter<'_>) -> fmt::Result + Send + Sync),
+}
+
+impl fmt::Display for FormatWith<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self, f)
+        (self.format)(self.error, f)
     }
 }
 
 /// Encapsulates how error sources are indented and formatted.
+///
+/// Newlines are only written once they are followed by more text, so that trailing newlines in an
+/// error's message don't leave blank lines in the report.
 struct Indented<'a, D: ?Sized> {
     inner: &'a mut D,
     needs_indent: bool,
+    /// Whether no line has been indented yet, across every write.
+    first_line: bool,
+    /// The number of newlines that haven't been written yet.
+    pending_newlines: usize,
+    /// The number of spaces that haven't been written yet, because nothing followed them on
+    /// their line so far.
+    pending_spaces: usize,
     format: Format,
 }
 
+impl<'a, D> Indented<'a, D>
+where
+    D: Write + ?Sized,
+{
+    fn new(inner: &'a mut D, format: Format) -> Self {
+        Indented {
+            inner,
+            needs_indent: true,
+            first_line: true,
+            pending_newlines: 0,
+            pending_spaces: 0,
+            format,
+        }
+    }
+}
+
 /// The possible variants that error sources can be formatted as.
 #[derive(Clone, Copy)]
 enum Format {
     /// Insert uniform indentation before every line.
     ///
-    /// This format takes a static string as input and inserts it after every newline.
+    /// This format inserts the given number of spaces after every newline.
     Uniform {
-        /// The string to insert as indentation.
-        indentation: &'static str,
+        /// The number of spaces to insert as indentation.
+        width: usize,
     },
     /// Inserts a number before the first line.
     ///
-    /// This format hard codes the indentation level to match the indentation from
-    /// `std::backtrace::Backtrace`.
+    /// The number is right aligned within the indentation width, which by default matches the
+    /// indentation from `std::backtrace::Backtrace`.
     Numbered {
         /// The index to insert before the first line of output.
         ind: usize,
+        /// The width the index is right aligned within.
+        width: usize,
+        /// Whether the index should be styled with ANSI escape codes.
+        color: bool,
     },
 }
 
+/// The ANSI styles applied to parts of the multi-line format when color is enabled.
+#[derive(Clone, Copy)]
+enum Style {
+    /// Used for the wrapped error's message.
+    Bold,
+    /// Used for the `Caused by:` header.
+    Dimmed,
+    /// Used for the indices of numbered causes.
+    Index,
+}
+
+impl Style {
+    /// The escape code that resets all styling.
+    const RESET: &'static str = "\x1b[0m";
+
+    /// The escape code that starts this style.
+    fn escape_code(self) -> &'static str {
+        match self {
+            Style::Bold => "\x1b[1m",
+            Style::Dimmed => "\x1b[2m",
+            Style::Index => "\x1b[36m",
+        }
+    }
+}
+
 impl<D> Write for Indented<'_, D>
 where
     D: Write + ?Sized,
@@ -492,21 +5663,41 @@ where
     fn write_str(&mut self, s: &str) -> fmt::Result {
         for (ind, line) in s.split('\n').enumerate() {
             if ind > 0 {
-                self.inner.write_char('\n')?;
+                self.pending_newlines += 1;
+                self.pending_spaces = 0;
                 self.needs_indent = true;
             }
 
-            if self.needs_indent {
-                // Don't render the line unless its actually got text on it
-                if line.is_empty() {
-                    continue;
-                }
+            // Hold back trailing spaces until more text follows them on the same line
+            let text = line.trim_end_matches(' ');
+            let spaces = line.len() - text.len();
+
+            // Don't render the line unless its actually got text on it
+            if text.is_empty() {
+                self.pending_spaces += spaces;
+                continue;
+            }
+
+            for _ in 0..self.pending_newlines {
+                self.inner.write_char('\n')?;
+            }
+            self.pending_newlines = 0;
 
-                self.format.insert_indentation(ind, &mut self.inner)?;
+            if self.needs_indent {
+                self.format
+                    .insert_indentation(self.first_line, &mut self.inner)?;
                 self.needs_indent = false;
+                self.first_line = false;
             }
 
-            self.inner.write_fmt(format_args!("{}", line))?;
+            write!(
+                self.inner,
+                "{:width$}{}",
+                "",
+                text,
+                width = self.pending_spaces
+            )?;
+            self.pending_spaces = spaces;
         }
 
         Ok(())
@@ -515,20 +5706,376 @@ where
 
 impl Format {
     /// Write the specified formatting to the write buffer.
-    fn insert_indentation(&mut self, line: usize, f: &mut dyn Write) -> fmt::Result {
-        match self {
-            Format::Uniform { indentation } => {
-                write!(f, "{}", indentation)
-            }
-            Format::Numbered { ind } => {
-                if line == 0 {
-                    write!(f, "{: >4}: ", ind)?;
-                    *ind += 1;
-                    Ok(())
+    fn insert_indentation(&self, first_line: bool, f: &mut dyn Write) -> fmt::Result {
+        match *self {
+            Format::Uniform { width } => {
+                write!(f, "{:width$}", "", width = width)
+            }
+            Format::Numbered { ind, width, color } => {
+                if first_line {
+                    if color {
+                        f.write_str(Style::Index.escape_code())?;
+                    }
+                    write!(f, "{: >width$}:", ind, width = width)?;
+                    if color {
+                        f.write_str(Style::RESET)?;
+                    }
+                    f.write_char(' ')
                 } else {
-                    write!(f, "      ")
+                    write!(f, "{:width$}", "", width = width + 2)
                 }
             }
         }
     }
 }
+
+/// An error from the chain of a report, along with the same error as the `'static` trait object
+/// the hooks are given, so that they can downcast it.
+///
+/// Every source can be viewed as one, but the wrapped error only once its type is known to be
+/// `'static`.
+#[derive(Clone, Copy)]
+struct Link<'a> {
+    error: &'a dyn Error,
+    as_static: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> From<&'a (dyn Error + 'static)> for Link<'a> {
+    fn from(error: &'a (dyn Error + 'static)) -> Self {
+        Link {
+            error,
+            as_static: Some(error),
+        }
+    }
+}
+
+/// The name of the type of an error, as shown in the entries of a report.
+trait TypeName {
+    fn type_name(&self) -> &'static str;
+}
+
+#[cfg(not(feature = "dyn_error"))]
+impl<E> TypeName for E {
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<E>()
+    }
+}
+
+#[cfg(feature = "dyn_error")]
+impl<E> TypeName for E {
+    default fn type_name(&self) -> &'static str {
+        std::any::type_name::<E>()
+    }
+}
+
+// A `DynError` already recorded the type of the error it was created from
+#[cfg(feature = "dyn_error")]
+impl TypeName for DynError {
+    fn type_name(&self) -> &'static str {
+        DynError::type_name(self)
+    }
+}
+
+/// View `error` as a `'static` trait object.
+fn as_static<E>(error: &E) -> &(dyn Error + 'static)
+where
+    E: Error + 'static,
+{
+    error
+}
+
+/// Iterator over the sources of an error that stops at the first source which repeats an error
+/// already seen earlier in the chain, so that cyclic `source` impls can't cause an infinite loop.
+struct Sources<'a> {
+    top: &'a dyn Error,
+    next: Option<&'a (dyn Error + 'static)>,
+    yielded: usize,
+    /// The number of sources before the first repeated one, if the chain has a cycle, found on
+    /// the first call to `next`.
+    cycle_at: Option<Option<usize>>,
+    cycle_detected: bool,
+}
+
+impl<'a> Sources<'a> {
+    fn new(top: &'a dyn Error) -> Self {
+        Sources {
+            top,
+            next: top.source(),
+            yielded: 0,
+            cycle_at: None,
+            cycle_detected: false,
+        }
+    }
+
+    /// Find where the chain starting at `top` first repeats an error, using Brent's cycle
+    /// detection so that the chain is only walked a constant number of times.
+    ///
+    /// Returns the number of sources that come before the first repeated one, or `None` if the
+    /// chain ends without repeating itself.
+    fn find_cycle(top: &'a dyn Error) -> Option<usize> {
+        let step = |error: &'a dyn Error| error.source().map(|source| source as &dyn Error);
+
+        // Find the length of the cycle
+        let mut power = 1;
+        let mut length = 1;
+        let mut tortoise = top;
+        let mut hare = step(top)?;
+        while !same_error(tortoise, hare) {
+            if power == length {
+                tortoise = hare;
+                power *= 2;
+                length = 0;
+            }
+            hare = step(hare)?;
+            length += 1;
+        }
+
+        // Find where the cycle starts, with the hare a full cycle ahead of the tortoise
+        let mut tortoise = top;
+        let mut hare = top;
+        for _ in 0..length {
+            hare = step(hare)?;
+        }
+        let mut start = 0;
+        while !same_error(tortoise, hare) {
+            tortoise = step(tortoise)?;
+            hare = step(hare)?;
+            start += 1;
+        }
+
+        // The error at `start + length` is the first one seen twice, and the top error isn't
+        // counted as a source
+        Some(start + length - 1)
+    }
+}
+
+impl<'a> Iterator for Sources<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let error = self.next.take()?;
+
+        let top = self.top;
+        let cycle_at = *self.cycle_at.get_or_insert_with(|| Self::find_cycle(top));
+        if Some(self.yielded) == cycle_at {
+            self.cycle_detected = true;
+            return None;
+        }
+
+        self.next = error.source();
+        self.yielded += 1;
+        Some(error)
+    }
+}
+
+/// Iterator over the sources of an error that yields each source along with the length of the run
+/// of consecutive sources it stands for.
+///
+/// Runs are only collapsed when given a function to render the sources with, in which case a run
+/// is every consecutive source that renders identically. Otherwise every run has a length of one.
+struct Runs<'a, 'r> {
+    sources: Sources<'a>,
+    peeked: Option<&'a (dyn Error + 'static)>,
+    render: Option<&'r dyn Fn(&(dyn Error + 'static)) -> String>,
+}
+
+impl<'a, 'r> Runs<'a, 'r> {
+    fn new(
+        top: &'a dyn Error,
+        render: Option<&'r dyn Fn(&(dyn Error + 'static)) -> String>,
+    ) -> Self {
+        Runs {
+            sources: Sources::new(top),
+            peeked: None,
+            render,
+        }
+    }
+
+    /// The number of sources that haven't been yielded yet, regardless of runs.
+    fn remaining(self) -> usize {
+        usize::from(self.peeked.is_some()) + self.sources.count()
+    }
+}
+
+impl<'a, 'r> Iterator for Runs<'a, 'r> {
+    type Item = (&'a (dyn Error + 'static), usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let error = self.peeked.take().or_else(|| self.sources.next())?;
+        let render = match self.render {
+            Some(render) => render,
+            None => return Some((error, 1)),
+        };
+
+        let message = render(error);
+        let mut count = 1;
+        for next in self.sources.by_ref() {
+            if render(next) != message {
+                self.peeked = Some(next);
+                break;
+            }
+            count += 1;
+        }
+
+        Some((error, count))
+    }
+}
+
+/// Whether `a` and `b` refer to the same error.
+///
+/// The vtables are compared along with the addresses so that an error isn't mistaken for a field
+/// it returns as its source, which can live at the same address.
+#[allow(clippy::vtable_address_comparisons)]
+fn same_error(a: &dyn Error, b: &dyn Error) -> bool {
+    std::ptr::eq(a, b)
+}
+
+/// Whether `written` ends with the whole of `message`, either on its own or after a `: ` or the
+/// `separator` of the report, rather than with the tail of a longer message.
+fn ends_with_message(written: &str, message: &str, separator: &str) -> bool {
+    match written.strip_suffix(message) {
+        Some(rest) => {
+            rest.is_empty()
+                || rest.ends_with(": ")
+                || (!separator.is_empty() && rest.ends_with(separator))
+        }
+        None => false,
+    }
+}
+
+/// The number of digits in the decimal representation of `n`.
+fn decimal_digits(mut n: usize) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// Lowercase the first letter of `s`, unless it begins an acronym like `HTTP`.
+fn uncapitalize(s: &str) -> Cow<'_, str> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(first), second)
+            if first.is_uppercase() && !second.map_or(false, char::is_uppercase) =>
+        {
+            let rest = &s[first.len_utf8()..];
+            first
+                .to_lowercase()
+                .chain(rest.chars())
+                .collect::<String>()
+                .into()
+        }
+        _ => s.into(),
+    }
+}
+
+/// Shorten `s` to at most `max_bytes` bytes, ending it with `...` if anything was cut.
+fn truncate_with_marker(s: &mut String, max_bytes: usize) {
+    const MARKER: &str = "...";
+
+    if s.len() <= max_bytes {
+        return;
+    }
+
+    let mut end = max_bytes.saturating_sub(MARKER.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    s.truncate(end);
+    s.push_str(&MARKER[..max_bytes.min(MARKER.len())]);
+}
+
+/// Shorten `s` to at most `max_chars` characters, ending it with `...` if anything was cut.
+fn truncate_chars_with_marker(s: &mut String, max_chars: usize) {
+    const MARKER: &str = "...";
+
+    if s.chars().count() <= max_chars {
+        return;
+    }
+
+    let kept = max_chars.saturating_sub(MARKER.len());
+    let end = s.char_indices().nth(kept).map_or(s.len(), |(end, _)| end);
+
+    s.truncate(end);
+    s.push_str(&MARKER[..max_chars.min(MARKER.len())]);
+}
+
+/// Write `s` to `out` as a JSON string literal.
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Whether YAML 1.1 or 1.2 would read the plain scalar `s` as a null, bool or special float.
+fn is_yaml_keyword(s: &str) -> bool {
+    const KEYWORDS: &[&str] = &[
+        "~", "null", "true", "false", "yes", "no", "y", "n", "on", "off", ".inf", "+.inf", "-.inf",
+        ".nan",
+    ];
+
+    KEYWORDS
+        .iter()
+        .any(|keyword| s.eq_ignore_ascii_case(keyword))
+}
+
+/// Whether YAML 1.1 or 1.2 would read the plain scalar `s` as a number, including the hex,
+/// octal, binary, underscored and sexagesimal forms of ints.
+fn is_yaml_number(s: &str) -> bool {
+    let unsigned = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    let digits = unsigned.get(2..).unwrap_or_default();
+    let is_digits =
+        |radix| !digits.is_empty() && digits.chars().all(|c| c == '_' || c.is_digit(radix));
+
+    match unsigned.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") if is_digits(16) => return true,
+        Some("0o") if is_digits(8) => return true,
+        Some("0b") if is_digits(2) => return true,
+        _ => {}
+    }
+
+    // Sexagesimal ints and floats such as `1:30` are a YAML 1.1 feature
+    let sexagesimal = unsigned.starts_with(|c: char| c.is_ascii_digit())
+        && unsigned.contains(':')
+        && unsigned
+            .chars()
+            .all(|c| c.is_ascii_digit() || "_:.".contains(c));
+
+    sexagesimal || s.replace('_', "").parse::<f64>().is_ok()
+}
+
+/// Write `s` to `out` as a YAML scalar, quoting it if it would not round trip as a plain scalar.
+fn write_yaml_scalar(out: &mut String, s: &str) {
+    let needs_quotes = s.is_empty()
+        || s.starts_with(char::is_whitespace)
+        || s.ends_with(char::is_whitespace)
+        || s.starts_with(|c| "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        || s.contains(": ")
+        || s.contains(" #")
+        || s.ends_with(':')
+        || s.contains(char::is_control)
+        || is_yaml_keyword(s)
+        || is_yaml_number(s);
+
+    if needs_quotes {
+        // JSON strings are valid YAML double quoted scalars
+        write_json_string(out, s);
+    } else {
+        out.push_str(s);
+    }
+}
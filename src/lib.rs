@@ -6,14 +6,20 @@
 //! 2. An error reporter that wraps an error and handles iterating over sources
 //!    and formatting a full error report.
 //!
-#![feature(try_trait_v2)]
+//! The first experiment depends on a lot more unstable features than the second, so it is gated
+//! behind the `dyn_error` feature, which is disabled by default. Without it, only the error
+//! reporter is compiled.
+//!
+#![cfg_attr(feature = "dyn_error", feature(try_trait_v2))]
 #![feature(termination_trait_lib)]
-#![feature(never_type)]
-#![feature(control_flow_enum)]
-#![feature(box_patterns)]
-#![feature(exhaustive_patterns)]
+#![cfg_attr(feature = "dyn_error", feature(never_type))]
+#![cfg_attr(feature = "dyn_error", feature(control_flow_enum))]
+#![cfg_attr(feature = "dyn_error", feature(box_patterns))]
+#![cfg_attr(feature = "dyn_error", feature(exhaustive_patterns))]
 #![feature(backtrace)]
-#![feature(error_iter)]
+#![cfg_attr(feature = "dyn_error", feature(error_iter))]
+#![feature(once_cell)]
+#![cfg_attr(feature = "dyn_error", feature(min_specialization))]
 
 #![warn(
     missing_docs,
@@ -27,8 +33,10 @@
     unused_comparisons,
 )]
 
+#[cfg(feature = "dyn_error")]
 pub mod boxerror_replacement;
 pub mod error_reporter;
 
-pub use boxerror_replacement::{DynError, DynResult};
+#[cfg(feature = "dyn_error")]
+pub use boxerror_replacement::{DynError, DynResult, LocalDynError, LocalDynResult};
 pub use error_reporter::Report;